@@ -0,0 +1,637 @@
+//! Turns `data/authors.toml`, `data/works.toml` and `data/quotes.toml` into
+//! the `authors` and `works` modules, the `QUOTE_LIST` constant of
+//! `src/lib.rs` and the search index of `src/search.rs`
+//!
+//! Every entry is checked here so mistakes in the data files show up as a
+//! readable list of errors instead of a wall of compiler output.
+
+// `map_or` stands in for `Option::is_some_and` and `is_none_or`, which are
+// newer than the Rust versions the crate supports
+#![allow(clippy::unnecessary_map_or)]
+
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::{env, fs, process};
+
+#[allow(dead_code)]
+#[path = "src/toml.rs"]
+mod toml;
+
+#[allow(dead_code)]
+#[path = "src/tag.rs"]
+mod tag;
+
+#[allow(dead_code)]
+#[path = "src/rating.rs"]
+mod rating;
+
+#[allow(dead_code)]
+#[path = "src/work.rs"]
+mod work;
+
+#[path = "src/terms.rs"]
+mod terms;
+
+use rating::Rating;
+use tag::Tag;
+use toml::{Table, Value};
+use work::WorkKind;
+
+const QUOTES: &str = "data/quotes.toml";
+const AUTHORS: &str = "data/authors.toml";
+const WORKS: &str = "data/works.toml";
+
+/// Keys a `[[quote]]` may have
+const QUOTE_KEYS: [&str; 7] = [
+    "id",
+    "author",
+    "work",
+    "quote",
+    "permalink",
+    "tags",
+    "rating",
+];
+
+/// Keys an `[[author]]` may have
+const AUTHOR_KEYS: [&str; 7] = [
+    "const",
+    "name",
+    "aliases",
+    "members",
+    "born",
+    "died",
+    "description",
+];
+
+/// Keys a `[[work]]` may have
+const WORK_KEYS: [&str; 4] = ["const", "title", "year", "kind"];
+
+fn main() {
+    for path in [
+        QUOTES,
+        AUTHORS,
+        WORKS,
+        "src/tag.rs",
+        "src/rating.rs",
+        "src/work.rs",
+        "src/toml.rs",
+        "src/terms.rs",
+    ] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
+    let authors = entries(AUTHORS, "author", author);
+    let works = entries(WORKS, "work", work);
+    let names = Names {
+        authors: constants(AUTHORS, authors.iter().map(|a| (&a.ident, &a.name))),
+        works: constants(WORKS, works.iter().map(|w| (&w.ident, &w.title))),
+    };
+
+    let mut errors = Vec::new();
+    for author in &authors {
+        for member in &author.members {
+            if *member == author.name {
+                errors.push(format!("{}: can't be a member of itself", author.name));
+            } else if !names.authors.contains_key(member) {
+                errors.push(format!("{}: unknown member '{}'", author.name, member));
+            }
+        }
+    }
+    if !errors.is_empty() {
+        fail(AUTHORS, &errors);
+    }
+
+    // With the `sfw` feature crude quotes are checked, but left out
+    let sfw = env::var_os("CARGO_FEATURE_SFW").is_some();
+    let quotes = entries(QUOTES, "quote", |table| names.quote(table));
+    let mut ids = HashSet::new();
+    let mut code = String::new();
+    let mut texts = Vec::new();
+    for (id, rating, text, quote) in &quotes {
+        if !ids.insert(id) {
+            errors.push(format!("quote {}: id is used twice", id));
+        } else if !(sfw && *rating == Rating::Crude) {
+            code.push_str(quote);
+            texts.push(text.as_str());
+        }
+    }
+    if !errors.is_empty() {
+        fail(QUOTES, &errors);
+    }
+
+    write(
+        "quotes.rs",
+        format!(
+            "/// Contents of [`QUOTES`], kept as a `const` so the id index can be built at compile time\n\
+             #[allow(clippy::large_const_arrays)]\n\
+             const QUOTE_LIST: [Quote; {}] = [\n{}];\n",
+            texts.len(),
+            code
+        ),
+    );
+    write("index.rs", index_code(&texts));
+    write("authors.rs", authors_code(&authors, &names));
+    write("works.rs", works_code(&works));
+}
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| fail(path, &[format!("can't read it: {}", e)]))
+}
+
+fn write(name: &str, code: String) {
+    let path = format!(
+        "{}/{}",
+        env::var("OUT_DIR").expect("OUT_DIR is set by cargo"),
+        name
+    );
+    fs::write(&path, code).unwrap_or_else(|e| {
+        eprintln!("can't write {}: {}", path, e);
+        process::exit(1);
+    });
+}
+
+fn fail(file: &str, errors: &[String]) -> ! {
+    eprintln!("{} has {} error(s):", file, errors.len());
+    for error in errors {
+        eprintln!("    {}", error);
+    }
+    process::exit(1);
+}
+
+/// Check every `[[key]]` table of a data file, failing with all of their errors
+fn entries<T>(file: &str, key: &str, check: impl Fn(&Table) -> Result<T, Vec<String>>) -> Vec<T> {
+    let mut root = toml::parse(&read(file)).unwrap_or_else(|e| fail(file, &[e.to_string()]));
+    let tables = match root.remove(key) {
+        Some(Value::Array(tables)) => tables,
+        _ => fail(file, &[format!("expected a list of [[{}]] tables", key)]),
+    };
+    if let Some(other) = root.keys().next() {
+        fail(
+            file,
+            &[format!(
+                "unknown key '{}', expected only [[{}]] tables",
+                other, key
+            )],
+        );
+    }
+
+    let mut errors = Vec::new();
+    let mut found = Vec::new();
+    for (i, table) in tables.iter().enumerate() {
+        let result = match table {
+            Value::Table(table) => check(table),
+            _ => Err(vec![format!(
+                "expected a table, found {}",
+                table.type_name()
+            )]),
+        };
+        match result {
+            Ok(entry) => found.push(entry),
+            Err(e) => {
+                // Point at the entry by what identifies it best
+                let at = match table {
+                    Value::Table(table) => match (table.get("id"), table.get("const")) {
+                        (Some(Value::Integer(id)), _) => format!("{} {}", key, id),
+                        (_, Some(Value::String(name))) => name.clone(),
+                        _ => format!("[[{}]] number {}", key, i + 1),
+                    },
+                    _ => format!("[[{}]] number {}", key, i + 1),
+                };
+                errors.extend(e.into_iter().map(|e| format!("{}: {}", at, e)));
+            }
+        }
+    }
+    if !errors.is_empty() {
+        fail(file, &errors);
+    }
+    found
+}
+
+/// An `[[author]]` of `data/authors.toml`
+struct AuthorData {
+    ident: String,
+    name: String,
+    aliases: Vec<String>,
+    members: Vec<String>,
+    born: Option<i16>,
+    died: Option<i16>,
+    description: Option<String>,
+}
+
+/// A `[[work]]` of `data/works.toml`
+struct WorkData {
+    ident: String,
+    title: String,
+    year: Option<u16>,
+    kind: WorkKind,
+}
+
+fn author(table: &Table) -> Result<AuthorData, Vec<String>> {
+    let mut errors = unknown_keys(table, &AUTHOR_KEYS);
+    let ident = ident(table, &mut errors);
+    let name = text(table, "name", &mut errors);
+    let aliases = list(table, "aliases", &mut errors);
+    let members = list(table, "members", &mut errors);
+    let born = year(table, "born", &mut errors);
+    let died = year(table, "died", &mut errors);
+    let description = match table.get("description") {
+        None => None,
+        Some(_) => text(table, "description", &mut errors),
+    };
+    match (ident, name) {
+        (Some(ident), Some(name)) if errors.is_empty() => Ok(AuthorData {
+            ident,
+            name,
+            aliases,
+            members,
+            born,
+            died,
+            description,
+        }),
+        _ => Err(errors),
+    }
+}
+
+fn work(table: &Table) -> Result<WorkData, Vec<String>> {
+    let mut errors = unknown_keys(table, &WORK_KEYS);
+    let ident = ident(table, &mut errors);
+    let title = text(table, "title", &mut errors);
+    let year = year(table, "year", &mut errors).and_then(|year| {
+        let year = u16::try_from(year).ok();
+        if year.is_none() {
+            errors.push("'year' can't be negative".to_string());
+        }
+        year
+    });
+    let kind = string(table, "kind", &mut errors).and_then(|kind| {
+        let found = WorkKind::ALL
+            .iter()
+            .copied()
+            .find(|k| k.to_string() == *kind);
+        if found.is_none() {
+            let kinds: Vec<String> = WorkKind::ALL.iter().map(|k| k.to_string()).collect();
+            errors.push(format!(
+                "unknown kind '{}', expected one of: {}",
+                kind,
+                kinds.join(", ")
+            ));
+        }
+        found
+    });
+    match (ident, title, kind) {
+        (Some(ident), Some(title), Some(kind)) if errors.is_empty() => Ok(WorkData {
+            ident,
+            title,
+            year,
+            kind,
+        }),
+        _ => Err(errors),
+    }
+}
+
+/// Constant of every author or work, keyed by the name quotes refer to them by
+fn constants<'a>(
+    file: &str,
+    entries: impl Iterator<Item = (&'a String, &'a String)>,
+) -> BTreeMap<String, String> {
+    let mut errors = Vec::new();
+    let mut idents = HashSet::new();
+    let mut found = BTreeMap::new();
+    for (ident, name) in entries {
+        if !idents.insert(ident) {
+            errors.push(format!("{}: constant is used twice", ident));
+        }
+        if found.insert(name.clone(), ident.clone()).is_some() {
+            errors.push(format!("{}: is listed twice", name));
+        }
+    }
+    if !errors.is_empty() {
+        fail(file, &errors);
+    }
+    found
+}
+
+fn authors_code(authors: &[AuthorData], names: &Names) -> String {
+    let mut code = String::new();
+    for author in authors {
+        let members: Vec<String> = author
+            .members
+            .iter()
+            .map(|name| format!("&{}", names.authors[name]))
+            .collect();
+        writeln!(code, "pub const {}: Author = Author {{", author.ident).unwrap();
+        writeln!(code, "    name: Cow::Borrowed({:?}),", author.name).unwrap();
+        writeln!(code, "    aliases: &{:?},", author.aliases).unwrap();
+        writeln!(code, "    members: &[{}],", members.join(", ")).unwrap();
+        writeln!(code, "    born: {:?},", author.born).unwrap();
+        writeln!(code, "    died: {:?},", author.died).unwrap();
+        writeln!(code, "    description: {:?},", author.description).unwrap();
+        writeln!(code, "}};\n").unwrap();
+    }
+    let mut sorted: Vec<&AuthorData> = authors.iter().collect();
+    sorted.sort_by(|a, b| a.name.cmp(&b.name));
+    let list: Vec<&str> = sorted.iter().map(|a| a.ident.as_str()).collect();
+    writeln!(
+        code,
+        "/// Every author, sorted by name\npub(crate) const LIST: [Author; {}] = [{}];",
+        list.len(),
+        list.join(", ")
+    )
+    .unwrap();
+    code
+}
+
+fn works_code(works: &[WorkData]) -> String {
+    let mut code = String::new();
+    for work in works {
+        writeln!(code, "pub const {}: Work = Work {{", work.ident).unwrap();
+        writeln!(code, "    title: {:?},", work.title).unwrap();
+        writeln!(code, "    year: {:?},", work.year).unwrap();
+        writeln!(code, "    kind: WorkKind::{:?},", work.kind).unwrap();
+        writeln!(code, "}};\n").unwrap();
+    }
+    let list: Vec<&str> = works.iter().map(|w| w.ident.as_str()).collect();
+    writeln!(
+        code,
+        "/// Every work, sorted by title\npub(crate) const LIST: [Work; {}] = [{}];",
+        list.len(),
+        list.join(", ")
+    )
+    .unwrap();
+    code
+}
+
+/// Search index over the text of the quotes, see `Index::new` in `src/search.rs`
+fn index_code(texts: &[&str]) -> String {
+    let mut postings: BTreeMap<String, Vec<(usize, u32)>> = BTreeMap::new();
+    let mut lengths = Vec::with_capacity(texts.len());
+    for (pos, text) in texts.iter().enumerate() {
+        let mut length = 0;
+        for (_, term) in terms::terms(text) {
+            length += 1;
+            let list = postings.entry(term).or_default();
+            match list.last_mut() {
+                Some((last, count)) if *last == pos => *count += 1,
+                _ => list.push((pos, 1)),
+            }
+        }
+        lengths.push(length.to_string());
+    }
+
+    let mut code = String::new();
+    writeln!(
+        code,
+        "/// Every term of the quotes, sorted, with the position of each quote containing it and how often it does\n\
+         #[allow(clippy::large_const_arrays)]\n\
+         const POSTINGS: [(&str, &[(usize, u32)]); {}] = [",
+        postings.len()
+    )
+    .unwrap();
+    for (term, list) in &postings {
+        let list: Vec<String> = list
+            .iter()
+            .map(|(pos, count)| format!("({}, {})", pos, count))
+            .collect();
+        writeln!(code, "    ({:?}, &[{}]),", term, list.join(", ")).unwrap();
+    }
+    writeln!(code, "];\n").unwrap();
+    writeln!(
+        code,
+        "/// Number of terms in each quote\nconst LENGTHS: [u32; {}] = [{}];",
+        lengths.len(),
+        lengths.join(", ")
+    )
+    .unwrap();
+    code
+}
+
+/// Rust names of the authors and works a quote can refer to, keyed by how the data file refers to them
+struct Names {
+    authors: BTreeMap<String, String>,
+    works: BTreeMap<String, String>,
+}
+
+impl Names {
+    /// Check a `[[quote]]` table, giving its id, rating, text and the Rust code for it
+    fn quote(&self, table: &Table) -> Result<(u32, Rating, String, String), Vec<String>> {
+        let mut errors = unknown_keys(table, &QUOTE_KEYS);
+
+        let id = match table.get("id") {
+            Some(Value::Integer(id)) if *id > 0 && *id <= i64::from(u32::MAX) => *id as u32,
+            Some(Value::Integer(id)) => {
+                errors.push(format!("id {} is out of range", id));
+                0
+            }
+            _ => {
+                errors.push(expected(table, "id", "a positive integer"));
+                0
+            }
+        };
+        let author = string(table, "author", &mut errors)
+            .and_then(|name| lookup(&self.authors, "author", name, AUTHORS, &mut errors));
+        let work = match table.get("work") {
+            None => Some("None".to_string()),
+            Some(_) => string(table, "work", &mut errors)
+                .and_then(|title| lookup(&self.works, "work", title, WORKS, &mut errors))
+                .map(|work| format!("Some(works::{})", work)),
+        };
+        let quote = text(table, "quote", &mut errors);
+        let permalink = string(table, "permalink", &mut errors).and_then(|url| {
+            let origin = origin(url);
+            if origin.is_none() {
+                errors.push(format!("'{}' is not a permalink of a known origin", url));
+            }
+            origin
+        });
+        let tags = match table.get("tags") {
+            Some(Value::Array(tags)) if !tags.is_empty() => {
+                let mut found: Vec<Tag> = Vec::new();
+                for tag in tags {
+                    match tag {
+                        Value::String(name) => match name.parse::<Tag>() {
+                            Ok(tag) if found.contains(&tag) => {
+                                errors.push(format!("tag '{}' is listed twice", name))
+                            }
+                            Ok(tag) => found.push(tag),
+                            Err(e) => errors.push(e.to_string()),
+                        },
+                        _ => errors.push(format!("expected tag names, found {}", tag.type_name())),
+                    }
+                }
+                let tags: Vec<String> = found.iter().map(|tag| format!("Tag::{:?}", tag)).collect();
+                Some(tags.join(", "))
+            }
+            _ => {
+                errors.push(expected(table, "tags", "a list of at least one tag"));
+                None
+            }
+        };
+        let rating = string(table, "rating", &mut errors).and_then(|name| {
+            let rating = Rating::ALL.iter().copied().find(|r| r.to_string() == *name);
+            if rating.is_none() {
+                errors.push(format!(
+                    "unknown rating '{}', expected one of: safe, controversial, crude",
+                    name
+                ));
+            }
+            rating
+        });
+
+        match (author, work, quote, permalink, tags, rating) {
+            (Some(author), Some(work), Some(quote), Some(permalink), Some(tags), Some(rating))
+                if errors.is_empty() =>
+            {
+                let mut code = String::new();
+                writeln!(code, "    Quote {{").unwrap();
+                writeln!(code, "        author: authors::{},", author).unwrap();
+                writeln!(code, "        work: {},", work).unwrap();
+                writeln!(code, "        id: {},", id).unwrap();
+                writeln!(code, "        quote: Cow::Borrowed({:?}),", quote).unwrap();
+                writeln!(code, "        permalink: {},", permalink).unwrap();
+                writeln!(code, "        tags: Cow::Borrowed(&[{}]),", tags).unwrap();
+                writeln!(code, "        rating: Rating::{:?},", rating).unwrap();
+                writeln!(code, "    }},").unwrap();
+                Ok((id, rating, quote, code))
+            }
+            _ => Err(errors),
+        }
+    }
+}
+
+fn unknown_keys(table: &Table, keys: &[&str]) -> Vec<String> {
+    table
+        .keys()
+        .filter(|key| !keys.contains(&key.as_str()))
+        .map(|key| format!("unknown key '{}'", key))
+        .collect()
+}
+
+fn expected(table: &Table, key: &str, what: &str) -> String {
+    match table.get(key) {
+        None => format!("'{}' is missing", key),
+        Some(value) => format!("'{}' should be {}, found {}", key, what, value.type_name()),
+    }
+}
+
+fn string<'a>(table: &'a Table, key: &str, errors: &mut Vec<String>) -> Option<&'a String> {
+    match table.get(key) {
+        Some(Value::String(s)) => Some(s),
+        _ => {
+            errors.push(expected(table, key, "a string"));
+            None
+        }
+    }
+}
+
+/// String that is not empty and doesn't start or end with whitespace
+fn text(table: &Table, key: &str, errors: &mut Vec<String>) -> Option<String> {
+    let s = string(table, key, errors)?;
+    if s.is_empty() || s.trim() != s {
+        errors.push(format!(
+            "'{}' is empty or starts or ends with whitespace",
+            key
+        ));
+        return None;
+    }
+    Some(s.clone())
+}
+
+/// Optional list of strings, empty if missing
+fn list(table: &Table, key: &str, errors: &mut Vec<String>) -> Vec<String> {
+    match table.get(key) {
+        None => Vec::new(),
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(|value| match value {
+                Value::String(s) => Some(s.clone()),
+                _ => {
+                    errors.push(format!("'{}' should only have strings", key));
+                    None
+                }
+            })
+            .collect(),
+        Some(_) => {
+            errors.push(expected(table, key, "a list of strings"));
+            Vec::new()
+        }
+    }
+}
+
+/// Optional year
+fn year(table: &Table, key: &str, errors: &mut Vec<String>) -> Option<i16> {
+    match table.get(key) {
+        None => None,
+        Some(Value::Integer(year)) => {
+            let year = i16::try_from(*year).ok();
+            if year.is_none() {
+                errors.push(format!("'{}' is out of range", key));
+            }
+            year
+        }
+        Some(_) => {
+            errors.push(expected(table, key, "a year"));
+            None
+        }
+    }
+}
+
+/// Name of the Rust constant, e.g. `ALAN_KAY`
+fn ident(table: &Table, errors: &mut Vec<String>) -> Option<String> {
+    let ident = string(table, "const", errors)?;
+    let valid = ident.starts_with(|c: char| c.is_ascii_uppercase())
+        && ident
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        errors.push(format!(
+            "'{}' is not an uppercase Rust constant name",
+            ident
+        ));
+        return None;
+    }
+    Some(ident.clone())
+}
+
+fn lookup(
+    names: &BTreeMap<String, String>,
+    what: &str,
+    name: &str,
+    file: &str,
+    errors: &mut Vec<String>,
+) -> Option<String> {
+    let found = names.get(name).cloned();
+    if found.is_none() {
+        errors.push(format!(
+            "unknown {} '{}', it needs to be added to {}",
+            what, name, file
+        ));
+    }
+    found
+}
+
+/// Rust code for the [`Origin`] of a permalink, the reverse of its `Display`
+fn origin(url: &str) -> Option<String> {
+    let number = |prefix: &str| url.strip_prefix(prefix).and_then(|n| n.parse::<u32>().ok());
+    if let Some(n) = number("https://www.azquotes.com/quote/") {
+        Some(format!("Origin::AZQuotesQuote({})", n))
+    } else if let Some(author) = url.strip_prefix("https://www.azquotes.com/author/") {
+        Some(format!("Origin::AZQuotesAuthor({:?})", author)).filter(|_| !author.is_empty())
+    } else if let Some(n) = number("http://quotes.stormconsultancy.co.uk/quotes/") {
+        u8::try_from(n)
+            .ok()
+            .map(|n| format!("Origin::StormConsultancy({})", n))
+    } else {
+        match url {
+            "https://www.goodreads.com/quotes/tag/programming" => Some("Origin::GoodReads"),
+            "https://www.journaldev.com/240/my-25-favorite-programming-quotes-that-are-funny-too" => {
+                Some("Origin::JournalDev")
+            }
+            "https://github.com/mhinz/vim-startify/blob/master/autoload/startify/fortune.vim" => {
+                Some("Origin::VimStartify")
+            }
+            _ => None,
+        }
+        .map(String::from)
+    }
+}
@@ -1,93 +1,658 @@
-use ansi_term::Colour::{Green, Red, Yellow};
-use pquote::{Quote, QUOTES};
-use rand::Rng;
-use textwrap::Wrapper;
+// `map_or` stands in for `Option::is_some_and` and `is_none_or`, which are
+// newer than the Rust versions the crate supports
+#![allow(clippy::unnecessary_map_or)]
+
+use pquote::{
+    Color, ColorChoice, ColorDepth, Format, Frame, GroupBy, Index, OriginKind, Query, Quote,
+    QuoteBook, Rating, Style, Tag, Template, Theme, Themes, Wrap,
+};
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+const RED: Color = Color::Ansi(1);
+const GREEN: Color = Color::Ansi(2);
+const YELLOW: Color = Color::Ansi(3);
+
+// Colours of stdout and stderr, set once --color is parsed
+static DEPTH: OnceLock<(ColorDepth, ColorDepth)> = OnceLock::new();
+
+// Subcommands, `random` when none is given
+const COMMANDS: [&str; 9] = [
+    "random", "show", "list", "search", "authors", "origins", "stats", "export", "help",
+];
+
+#[derive(Debug)]
+struct AppArgs {
+    theme: Option<String>,
+    fortune_file: Option<String>,
+    fortune_rating: Rating,
+    command: Command,
+}
 
 #[derive(Debug)]
-enum AppArgs {
-    Flags {
-        help: bool,
-        version: bool,
+enum Command {
+    Help,
+    Version,
+    Random {
+        filters: Filters,
+        daily: bool,
+        no_repeat: bool,
+        seed: Option<u64>,
+        output: Output,
+    },
+    Show {
+        ids: Vec<u32>,
+        safe: bool,
+        output: Output,
+    },
+    List {
+        filters: Filters,
+        output: Output,
+    },
+    Search {
+        filters: Filters,
         verbose: bool,
-        all: bool,
-        id: Option<u32>,
-        author: Option<String>,
-        origin: Option<String>,
+        terms: Vec<String>,
+    },
+    Authors {
+        filters: Filters,
+    },
+    Origins {
+        filters: Filters,
     },
+    Stats {
+        filters: Filters,
+    },
+    Export {
+        filters: Filters,
+        kind: ExportKind,
+        by: GroupBy,
+        title: String,
+        file: Option<String>,
+    },
+}
+
+// Quotes a command picks from, every filter given has to match
+#[derive(Debug)]
+struct Filters {
+    safe: bool,
+    author: Option<String>,
+    origin: Option<OriginKind>,
+    tags: Vec<Tag>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    // Lines and the width they are wrapped at
+    max_lines: Option<(usize, usize)>,
+}
+
+// How quotes are printed, plain unless asked otherwise
+#[derive(Debug)]
+struct Output {
+    verbose: bool,
+    format: Option<Format>,
+    template: Option<Template>,
+    frame: Option<Frame>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ExportKind {
+    Html,
+    Markdown,
+    Fortune,
+}
+
+// Mistakes on the command line, pico-args' own or flags that don't go together
+#[derive(Debug)]
+enum ArgsError {
+    Args(pico_args::Error),
+    Invalid(String),
+}
+
+impl From<pico_args::Error> for ArgsError {
+    fn from(e: pico_args::Error) -> Self {
+        ArgsError::Args(e)
+    }
+}
+
+impl std::fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ArgsError::Args(e) => e.fmt(f),
+            ArgsError::Invalid(message) => f.write_str(message),
+        }
+    }
+}
+
+// e.g. "--format and --frame can't be used together"
+fn conflict(flags: &[&str]) -> ArgsError {
+    let (last, rest) = flags.split_last().unwrap_or((&"", &[]));
+    ArgsError::Invalid(format!(
+        "{} and {} can't be used together",
+        rest.join(", "),
+        last
+    ))
 }
 
 fn submain() -> AppArgs {
     let args = pico_args::Arguments::from_env();
 
-    match parse_flags(args) {
+    match parse_args(args) {
         Ok(a) => a,
         Err(e) => {
             eprintln!(
                 "{} {}
 {}
     pquote {}",
-                Red.paint("Error:"),
+                epaint(RED, "Error:"),
                 e,
-                Yellow.paint("USAGE:"),
-                Green.paint("--help")
+                epaint(YELLOW, "USAGE:"),
+                epaint(GREEN, "--help")
             );
             std::process::exit(1);
         }
     }
 }
 
-// Determines how the flags are parsed
-fn parse_flags(mut args: pico_args::Arguments) -> Result<AppArgs, pico_args::Error> {
-    let app_args = AppArgs::Flags {
-        help: args.contains(["-h", "--help"]),
-        version: args.contains(["-V", "--version"]),
-        verbose: args.contains(["-v", "--verbose"]),
-        all: args.contains(["-A", "--all"]),
-        id: args.opt_value_from_str(["-i", "--id"])?,
-        author: args.opt_value_from_str(["-a", "--author"])?,
-        origin: args.opt_value_from_str(["-o", "--origin"])?,
+// --color, --theme, --fortune-file and --fortune-rating go with every subcommand
+fn parse_args(mut args: pico_args::Arguments) -> Result<AppArgs, ArgsError> {
+    let color = args
+        .opt_value_from_str("--color")?
+        .unwrap_or(ColorChoice::Auto);
+    let _ = DEPTH.set((
+        color.depth(std::io::stdout().is_terminal()),
+        color.depth(std::io::stderr().is_terminal()),
+    ));
+    Ok(AppArgs {
+        theme: args.opt_value_from_str("--theme")?,
+        fortune_file: args.opt_value_from_str(["-f", "--fortune-file"])?,
+        fortune_rating: args
+            .opt_value_from_fn("--fortune-rating", parse_rating)?
+            .unwrap_or(Rating::Controversial),
+        command: parse_command(args)?,
+    })
+}
+
+// Determines how the flags of each subcommand are parsed
+fn parse_command(mut args: pico_args::Arguments) -> Result<Command, ArgsError> {
+    if args.contains(["-h", "--help"]) {
+        return Ok(Command::Help);
+    }
+    if args.contains(["-V", "--version"]) {
+        return Ok(Command::Version);
+    }
+
+    // Hidden aliases for the flags `list` and `show` used to be
+    let mut subcommand = args.subcommand()?;
+    let mut old_ids: Vec<u32> = Vec::new();
+    if subcommand.is_none() {
+        if args.contains(["-A", "--all"]) {
+            subcommand = Some("list".to_string());
+        } else {
+            old_ids = args.values_from_str(["-i", "--id"])?;
+            if !old_ids.is_empty() {
+                subcommand = Some("show".to_string());
+            }
+        }
+    }
+
+    let command = match subcommand.as_deref() {
+        None | Some("random") => {
+            let filters = Filters::parse(&mut args)?;
+            let daily = args.contains(["-d", "--daily"]);
+            let seed = args.opt_value_from_str(["-s", "--seed"])?;
+            let no_repeat = args.contains("--no-repeat");
+            let given: Vec<&str> = [
+                ("--daily", daily),
+                ("--seed", seed.is_some()),
+                ("--no-repeat", no_repeat),
+            ]
+            .iter()
+            .filter(|(_, given)| *given)
+            .map(|(flag, _)| *flag)
+            .collect();
+            if given.len() > 1 {
+                return Err(conflict(&given));
+            }
+            Command::Random {
+                filters,
+                daily,
+                no_repeat,
+                seed,
+                output: Output::parse(&mut args)?,
+            }
+        }
+        Some("show") => {
+            let safe = args.contains(["-S", "--safe"]);
+            let output = Output::parse(&mut args)?;
+            let mut ids = old_ids;
+            while let Some(id) = args.free_from_str()? {
+                ids.push(id);
+            }
+            if ids.is_empty() {
+                return Err(ArgsError::Invalid("show needs the id of a quote".into()));
+            }
+            Command::Show { ids, safe, output }
+        }
+        Some("list") => Command::List {
+            filters: Filters::parse(&mut args)?,
+            output: Output::parse(&mut args)?,
+        },
+        Some("search") => {
+            let filters = Filters::parse(&mut args)?;
+            let verbose = args.contains(["-v", "--verbose"]);
+            let terms = args.free()?;
+            if terms.is_empty() {
+                return Err(ArgsError::Invalid(
+                    "search needs something to search for".into(),
+                ));
+            }
+            return Ok(Command::Search {
+                filters,
+                verbose,
+                terms,
+            });
+        }
+        Some("authors") => Command::Authors {
+            filters: Filters::parse(&mut args)?,
+        },
+        Some("origins") => Command::Origins {
+            filters: Filters::parse(&mut args)?,
+        },
+        Some("stats") => Command::Stats {
+            filters: Filters::parse(&mut args)?,
+        },
+        Some("export") => {
+            let kinds = [
+                ("--html", ExportKind::Html),
+                ("--markdown", ExportKind::Markdown),
+                ("--fortune", ExportKind::Fortune),
+            ];
+            let given: Vec<(&str, ExportKind)> = kinds
+                .iter()
+                .copied()
+                .filter(|(flag, _)| args.contains(*flag))
+                .collect();
+            if given.len() > 1 {
+                let flags: Vec<&str> = given.iter().map(|(flag, _)| *flag).collect();
+                return Err(conflict(&flags));
+            }
+            // HTML unless asked for something else
+            let kind = given.first().map_or(ExportKind::Html, |(_, kind)| *kind);
+            let by = args.opt_value_from_fn(["-b", "--by"], parse_group)?;
+            let title = args.opt_value_from_str("--title")?;
+            if kind == ExportKind::Fortune {
+                if by.is_some() {
+                    return Err(conflict(&["--fortune", "--by"]));
+                }
+                if title.is_some() {
+                    return Err(conflict(&["--fortune", "--title"]));
+                }
+            }
+            Command::Export {
+                filters: Filters::parse(&mut args)?,
+                kind,
+                by: by.unwrap_or(GroupBy::Author),
+                title: title.unwrap_or_else(|| "Programmer Quotes".to_string()),
+                file: args.free_from_str()?,
+            }
+        }
+        Some("help") => Command::Help,
+        Some(other) => {
+            return Err(ArgsError::Invalid(format!(
+                "unknown command '{}', expected one of: {}",
+                other,
+                COMMANDS.join(", ")
+            )))
+        }
     };
 
     args.finish()?;
-    Ok(app_args)
+    Ok(command)
+}
+
+fn parse_rating(s: &str) -> Result<Rating, String> {
+    Rating::ALL
+        .iter()
+        .copied()
+        .find(|rating| rating.to_string().eq_ignore_ascii_case(s))
+        .ok_or_else(|| {
+            format!(
+                "unknown rating '{}', expected safe, controversial or crude",
+                s
+            )
+        })
+}
+
+fn parse_group(s: &str) -> Result<GroupBy, String> {
+    match s {
+        "author" => Ok(GroupBy::Author),
+        "origin" => Ok(GroupBy::Origin),
+        _ => Err(format!(
+            "unknown grouping '{}', expected author or origin",
+            s
+        )),
+    }
+}
+
+impl Filters {
+    fn parse(args: &mut pico_args::Arguments) -> Result<Filters, ArgsError> {
+        let safe = args.contains(["-S", "--safe"]);
+        let mut authors: Vec<String> = args.values_from_str(["-a", "--author"])?;
+        let mut origins: Vec<OriginKind> = args.values_from_str(["-o", "--origin"])?;
+        // A quote has one author and one origin, so a second would match nothing
+        if authors.len() > 1 {
+            return Err(ArgsError::Invalid("--author can only be given once".into()));
+        }
+        if origins.len() > 1 {
+            return Err(ArgsError::Invalid("--origin can only be given once".into()));
+        }
+        let min_length = args.opt_value_from_str("--min-length")?;
+        let max_length = args.opt_value_from_str("--max-length")?;
+        if let (Some(min), Some(max)) = (min_length, max_length) {
+            if min > max {
+                return Err(ArgsError::Invalid(
+                    "--min-length is more than --max-length".into(),
+                ));
+            }
+        }
+        // Wrapped as wide as the terminal, unless told otherwise
+        let max_lines: Option<usize> = args.opt_value_from_str("--max-lines")?;
+        let width: Option<usize> = args.opt_value_from_str("--width")?;
+        if max_lines.is_none() && width.is_some() {
+            return Err(ArgsError::Invalid(
+                "--width only goes with --max-lines".into(),
+            ));
+        }
+        Ok(Filters {
+            safe,
+            author: authors.pop(),
+            origin: origins.pop(),
+            tags: args.values_from_str(["-t", "--tag"])?,
+            min_length,
+            max_length,
+            max_lines: max_lines.map(|lines| (lines, width.unwrap_or_else(textwrap::termwidth))),
+        })
+    }
+
+    fn query(&self) -> Query {
+        let mut query = Query::new();
+        if self.safe {
+            query = query.safe();
+        }
+        if let Some(a) = &self.author {
+            query = query.author(a.as_str());
+        }
+        if let Some(o) = self.origin {
+            query = query.origin(o);
+        }
+        for &t in &self.tags {
+            query = query.tag(t);
+        }
+        if let Some(min) = self.min_length {
+            query = query.min_len(min);
+        }
+        if let Some(max) = self.max_length {
+            query = query.max_len(max);
+        }
+        if let Some((lines, width)) = self.max_lines {
+            query = query.max_lines(lines, width);
+        }
+        query
+    }
+
+    // Whether no filter was given, so every quote matches
+    fn is_empty(&self) -> bool {
+        !self.safe
+            && self.author.is_none()
+            && self.origin.is_none()
+            && self.tags.is_empty()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.max_lines.is_none()
+    }
+
+    // Quotes of the book matching every filter, in book order
+    fn apply(&self, book: &QuoteBook) -> Vec<Quote> {
+        book.query(&self.query()).cloned().collect()
+    }
+
+    // e.g. "Sorry no safe quotes found by Linus Torvalds from azquotes"
+    fn not_found(&self, book: &QuoteBook) -> String {
+        let mut message = String::from("Sorry no ");
+        if self.safe {
+            message.push_str("safe ");
+        }
+        message.push_str("quotes found");
+        if let Some(a) = &self.author {
+            message.push_str(&format!(" by {}", a));
+        }
+        if let Some(o) = self.origin {
+            message.push_str(&format!(" from {}", o));
+        }
+        if !self.tags.is_empty() {
+            let tags: Vec<&str> = self.tags.iter().map(|t| t.name()).collect();
+            message.push_str(&format!(" about {}", tags.join(" and ")));
+        }
+        match (self.min_length, self.max_length) {
+            (Some(min), Some(max)) => {
+                message.push_str(&format!(" of {} to {} characters", min, max))
+            }
+            (Some(min), None) => message.push_str(&format!(" of at least {} characters", min)),
+            (None, Some(max)) => message.push_str(&format!(" of at most {} characters", max)),
+            (None, None) => {}
+        }
+        if let Some((lines, width)) = self.max_lines {
+            message.push_str(&format!(
+                " that fit in {} lines of {} columns",
+                lines, width
+            ));
+        }
+        // Only worth suggesting if nobody by that name was found at all
+        let suggestion = self
+            .author
+            .as_ref()
+            .filter(|a| book.search_authors(a).is_empty())
+            .and_then(|a| book.suggest_author(a));
+        if let Some(s) = suggestion {
+            message.push_str(&format!(", did you mean {}?", paint(GREEN, &s.name)));
+        }
+        message
+    }
+}
+
+impl Output {
+    fn parse(args: &mut pico_args::Arguments) -> Result<Output, ArgsError> {
+        let output = Output {
+            verbose: args.contains(["-v", "--verbose"]),
+            format: args.opt_value_from_str(["-F", "--format"])?,
+            template: args.opt_value_from_str(["-T", "--template"])?,
+            frame: args.opt_value_from_str(["-B", "--frame"])?,
+        };
+        let given: Vec<&str> = [
+            ("--verbose", output.verbose),
+            ("--format", output.format.is_some()),
+            ("--template", output.template.is_some()),
+            ("--frame", output.frame.is_some()),
+        ]
+        .iter()
+        .filter(|(_, given)| *given)
+        .map(|(flag, _)| *flag)
+        .collect();
+        if given.len() > 1 {
+            return Err(conflict(&given));
+        }
+        Ok(output)
+    }
+
+    fn style(self, theme: Theme) -> OutputStyle {
+        match (self.format, self.template, self.frame) {
+            (Some(format), _, _) => OutputStyle::Format(format),
+            (_, Some(template), _) => OutputStyle::Template(template),
+            (_, _, Some(frame)) => OutputStyle::Frame(frame),
+            _ if self.verbose => OutputStyle::Verbose(theme),
+            _ => OutputStyle::Plain(theme),
+        }
+    }
 }
 
 fn help_print() {
+    let names = |names: Vec<&str>| names.join(",");
+    let origins = names(OriginKind::ALL.iter().map(|o| o.name()).collect());
+    let tags = names(Tag::ALL.iter().map(|t| t.name()).collect());
+    let formats = names(Format::ALL.iter().map(|f| f.name()).collect());
+    let frames = names(Frame::ALL.iter().map(|f| f.name()).collect());
+    let sections: [(&str, Vec<(&str, String)>); 6] = [
+        (
+            "COMMANDS:",
+            vec![
+                ("random", "Print a random quote, the default".into()),
+                ("show", "Print quotes by id".into()),
+                ("list", "Print every quote".into()),
+                ("search", "Search the text of the quotes".into()),
+                (
+                    "authors",
+                    "Print the authors and how many quotes they have".into(),
+                ),
+                (
+                    "origins",
+                    "Print where the quotes were found and how many".into(),
+                ),
+                ("stats", "Count the quotes by rating, origin and tag".into()),
+                (
+                    "export",
+                    "Write the quotes to a page or a fortune(6) file".into(),
+                ),
+            ],
+        ),
+        (
+            "FILTERS (every one given has to match):",
+            vec![
+                (
+                    "-S,--safe",
+                    "Only quotes that are safe to show anywhere".into(),
+                ),
+                ("-a,--author <author>", "Quotes by an author".into()),
+                (
+                    "-o,--origin <origin>",
+                    format!("Quotes from an origin ({})", origins),
+                ),
+                (
+                    "-t,--tag <tag>",
+                    format!("Quotes about a tag, repeatable ({})", tags),
+                ),
+                (
+                    "   --min-length <n>",
+                    "Quotes of at least n characters".into(),
+                ),
+                (
+                    "   --max-length <n>",
+                    "Quotes of at most n characters".into(),
+                ),
+                (
+                    "   --max-lines <n>",
+                    "Quotes that wrap to at most n lines".into(),
+                ),
+                (
+                    "   --width <w>",
+                    "Width --max-lines wraps at, the terminal's by default".into(),
+                ),
+            ],
+        ),
+        (
+            "RANDOM:",
+            vec![
+                (
+                    "-d,--daily",
+                    "Print the quote of the day (UTC), filters change the pick".into(),
+                ),
+                (
+                    "-s,--seed <seed>",
+                    "Choose by seed, same seed gives same quote".into(),
+                ),
+                (
+                    "   --no-repeat",
+                    "Show every quote once before any repeats".into(),
+                ),
+            ],
+        ),
+        (
+            "OUTPUT:",
+            vec![
+                ("-v,--verbose", "Print quotes verbosely".into()),
+                (
+                    "-F,--format <format>",
+                    format!("Print quotes in a machine-readable format ({})", formats),
+                ),
+                (
+                    "-T,--template <text>",
+                    "Print quotes with a template, e.g. '{quote} — {author}'".into(),
+                ),
+                (
+                    "-B,--frame <style>",
+                    format!("Draw a frame around quotes ({})", frames),
+                ),
+            ],
+        ),
+        (
+            "EXPORT:",
+            vec![
+                ("   --html", "Standalone HTML page, the default".into()),
+                ("   --markdown", "Markdown document".into()),
+                ("   --fortune", "fortune(6) file and its .dat index".into()),
+                (
+                    "-b,--by author|origin",
+                    "Put pages under a heading per author or origin".into(),
+                ),
+                ("   --title <title>", "Title of the page".into()),
+            ],
+        ),
+        (
+            "OPTIONS:",
+            vec![
+                (
+                    "-f,--fortune-file <file>",
+                    "Also choose from the fortunes in a fortune(6) file".into(),
+                ),
+                (
+                    "   --fortune-rating <r>",
+                    "Rate those fortunes safe, controversial (default) or crude".into(),
+                ),
+                (
+                    "   --color <when>",
+                    "Colour output: auto, always or never".into(),
+                ),
+                (
+                    "   --theme <theme>",
+                    format!("Colour theme ({} or your own)", Theme::BUILTIN.join(",")),
+                ),
+                ("-h,--help", "Prints help information".into()),
+                ("-V,--version", "Prints version information".into()),
+            ],
+        ),
+    ];
+
     println!(
         "{} {}
 Programmer Quote Generator
 
 {}
-    pquote <FLAGS>
-
-{}
-    {},{}                Prints help information
-    {},{}             Prints version information
-    {},{}             Prints quote verbosely
-    {},{}                 Print all quotes
-    {},{}             Choose quote by id
-    {},{}     Choose quote by author
-    {},{}     Choose quote by origin (azquotes,goodreads,journaldev,vimstartify,stormconsultancy)
-",
-        Green.paint("pquote"),
+    pquote [random] [FILTERS] [-d|-s <seed>|--no-repeat] [OUTPUT]
+    pquote show <id>... [-S] [OUTPUT]
+    pquote list [FILTERS] [OUTPUT]
+    pquote search [FILTERS] [-v] <terms>...
+    pquote authors|origins|stats [FILTERS]
+    pquote export [--html|--markdown|--fortune] [FILTERS] [--by author|origin] [--title <title>] [file]",
+        paint(GREEN, "pquote"),
         env!("CARGO_PKG_VERSION"),
-        Yellow.paint("USAGE:"),
-        Yellow.paint("FLAGS:"),
-        Green.paint("-h"),
-        Green.paint("--help"),
-        Green.paint("-V"),
-        Green.paint("--version"),
-        Green.paint("-v"),
-        Green.paint("--verbose"),
-        Green.paint("-A"),
-        Green.paint("--all"),
-        Green.paint("-i"),
-        Green.paint("--id <id>"),
-        Green.paint("-a"),
-        Green.paint("--author <author>"),
-        Green.paint("-o"),
-        Green.paint("--origin <origin>"),
+        paint(YELLOW, "USAGE:"),
     );
+    for (heading, rows) in sections.iter() {
+        println!("\n{}", paint(YELLOW, heading));
+        for (flag, text) in rows {
+            let fill = " ".repeat(24usize.saturating_sub(flag.len()));
+            println!("    {}{} {}", paint(GREEN, flag), fill, text);
+        }
+    }
 }
 
 fn version_print() {
@@ -95,146 +660,353 @@ fn version_print() {
 }
 
 fn main() {
-    // Get Random Quote
-    match submain() {
-        AppArgs::Flags {
-            help,
-            version,
-            verbose,
-            all,
-            id,
-            author,
-            origin,
+    let args = submain();
+    match args.command {
+        Command::Help => return help_print(),
+        Command::Version => return version_print(),
+        _ => {}
+    }
+
+    // Built-in quotes plus the user's own, if they have any
+    let mut book = QuoteBook::with_user_file().unwrap_or_else(|e| {
+        eprintln!("{} {}", epaint(YELLOW, "Warning:"), e);
+        QuoteBook::new()
+    });
+    if let Some(path) = args.fortune_file {
+        if let Err(e) = book.load_fortune(path, args.fortune_rating) {
+            fail(e);
+        }
+    }
+
+    // The default theme, unless one was asked for
+    let themes = Themes::with_user_file().unwrap_or_else(|e| {
+        eprintln!("{} {}", epaint(YELLOW, "Warning:"), e);
+        Themes::new()
+    });
+    let theme = match args.theme {
+        Some(name) => themes.get(&name).unwrap_or_else(|| {
+            fail(format!(
+                "unknown theme '{}', expected one of: {}",
+                name,
+                themes.names().join(", ")
+            ))
+        }),
+        None => themes.default_theme(),
+    };
+
+    match args.command {
+        Command::Help | Command::Version => {}
+        Command::Random {
+            filters,
+            daily,
+            no_repeat,
+            seed,
+            output,
         } => {
-            let num = if let Some(x) = id {
-                QUOTES.len() - x as usize
+            let pool = filters.apply(&book);
+            let q = if no_repeat {
+                // Shuffle bag kept between runs, locked against other shells
+                let path = pquote::rotation_file().unwrap_or_else(|| {
+                    fail("nowhere to keep --no-repeat's state, set XDG_STATE_HOME")
+                });
+                pquote::Rotation::next_in_file(&path, &pool)
+                    .unwrap_or_else(|e| fail(format!("{}: {}", path.display(), e)))
+            } else if daily && filters.is_empty() {
+                // The library's pick, the same wherever pq runs whatever quotes
+                // were added, unless it was disabled
+                let q = pquote::daily();
+                pool.iter()
+                    .find(|p| p.id == q.id)
+                    .or_else(|| pquote::choose_seeded(&pool, pquote::days_since_epoch()))
+            } else if daily {
+                pquote::choose_seeded(&pool, pquote::days_since_epoch())
+            } else if let Some(s) = seed {
+                pquote::choose_seeded(&pool, s)
             } else {
-                rand::thread_rng().gen_range(0, QUOTES.len())
+                pquote::choose(&pool)
             };
-            if help {
-                help_print();
-            } else if version {
-                version_print();
-            } else if let Some(identity) = id {
-                print_quote(identity as usize, verbose, all, None)
-            } else if let Some(a) = author {
-                let mut v: Vec<Quote> = Vec::with_capacity(1);
-                for quotes in QUOTES.iter() {
-                    if quotes.author == a {
-                        v.push(*quotes)
+            match q {
+                Some(q) => print_quote(q, &output.style(theme)),
+                None => println!("{}", filters.not_found(&book)),
+            }
+        }
+        Command::Show { ids, safe, output } => {
+            let mut quotes = Vec::new();
+            for id in ids {
+                match book.try_by_id(id) {
+                    Ok(q) if safe && !q.is_safe() => {
+                        fail(format!("quote {} is rated {}", id, q.rating))
                     }
+                    Ok(q) => quotes.push(q.clone()),
+                    Err(e) => fail(e),
                 }
-                match v.len() {
-                    1 => print_quote(0, verbose, all, Some(v)),
-                    l if l > 1 => {
-                        print_quote(
-                            rand::thread_rng().gen_range(0, v.len()),
-                            verbose,
-                            all,
-                            Some(v),
-                        );
-                    }
-                    _ => println!("Sorry no quotes found by {}", a),
+            }
+            match quotes.as_slice() {
+                [q] => print_quote(q, &output.style(theme)),
+                _ => print_list(&quotes, &output.style(theme)),
+            }
+        }
+        Command::List { filters, output } => {
+            let pool = filters.apply(&book);
+            if pool.is_empty() {
+                println!("{}", filters.not_found(&book));
+            } else {
+                print_list(&pool, &output.style(theme));
+            }
+        }
+        Command::Search {
+            filters,
+            verbose,
+            terms,
+        } => {
+            let query = terms.join(" ");
+            let pool = filters.apply(&book);
+            let hits = Index::new(&pool).search(&query);
+            if hits.is_empty() {
+                println!("Sorry no quotes found matching {}", query);
+            }
+            for hit in hits {
+                let text = highlight(
+                    &Wrap::new(textwrap::termwidth()).fill(&hit.quote.quote),
+                    &query,
+                    &theme,
+                );
+                if verbose {
+                    println!(
+                        "ID: {}\nScore: {:.2}\nQuote: {}\n{}\nLink: {}\n",
+                        theme.id.paint(&hit.quote.id.to_string(), depth()),
+                        hit.score,
+                        text,
+                        theme.author.paint(&attribution(hit.quote), depth()),
+                        theme.link.paint(&hit.quote.permalink.to_string(), depth()),
+                    );
+                } else {
+                    println!(
+                        "{}\n\n\t- {}\n",
+                        text,
+                        theme.author.paint(&hit.quote.author.name, depth())
+                    );
                 }
-            } else if let Some(o) = origin {
-                let mut v: Vec<Quote> = Vec::with_capacity(1);
-                match o.as_str() {
-                    "azquotes" | "azquote" | "AZQuote" | "AZQuotes" => {
-                        for quote in QUOTES.iter() {
-                            if quote.permalink.is_azquotes() {
-                                v.push(*quote)
-                            }
-                        }
-                    }
-                    "goodreads" | "good reads" | "Good Reads" | "GoodReads" => {
-                        for quote in QUOTES.iter() {
-                            if quote.permalink.is_goodreads() {
-                                v.push(*quote)
-                            }
-                        }
-                    }
-                    "journaldev" | "journal dev" | "Journaldev" | "Journal Dev" => {
-                        for quote in QUOTES.iter() {
-                            if quote.permalink.is_journaldev() {
-                                v.push(*quote)
-                            }
-                        }
-                    }
-                    "vimstartify" | "vim startify" | "Vim Startify" | "Vimstartify" => {
-                        for quote in QUOTES.iter() {
-                            if quote.permalink.is_vimstartify() {
-                                v.push(*quote)
-                            }
-                        }
-                    }
-                    "stormconsultancy" | "storm consultancy" | "Storm Consultancy"
-                    | "Stormconsultancy" => {
-                        for quote in QUOTES.iter() {
-                            if quote.permalink.is_stormconsultancy() {
-                                v.push(*quote)
-                            }
-                        }
-                    }
-                    _ => {}
-                };
-
-                match v.len() {
-                    1 => print_quote(0, verbose, all, Some(v)),
-                    l if l > 1 => {
-                        print_quote(
-                            rand::thread_rng().gen_range(0, v.len()),
-                            verbose,
-                            all,
-                            Some(v),
-                        );
+            }
+        }
+        Command::Authors { filters } => {
+            let pool = filters.apply(&book);
+            let mut authors: Vec<(&str, usize)> = Vec::new();
+            for quote in &pool {
+                match authors
+                    .iter_mut()
+                    .find(|(name, _)| *name == quote.author.name)
+                {
+                    Some((_, count)) => *count += 1,
+                    None => authors.push((&quote.author.name, 1)),
+                }
+            }
+            authors.sort_by_cached_key(|(name, _)| name.to_lowercase());
+            for (name, count) in authors {
+                println!("{:>4}  {}", count, theme.author.paint(name, depth()));
+            }
+        }
+        Command::Origins { filters } => {
+            let pool = filters.apply(&book);
+            for kind in OriginKind::ALL.iter() {
+                let count = pool.iter().filter(|q| q.permalink.kind() == *kind).count();
+                if count > 0 {
+                    println!("{:>4}  {}", count, kind);
+                }
+            }
+        }
+        Command::Stats { filters } => print_stats(&filters.apply(&book)),
+        Command::Export {
+            filters,
+            kind,
+            by,
+            title,
+            file,
+        } => {
+            let quotes = filters.apply(&book);
+            match (kind, file) {
+                (ExportKind::Fortune, Some(path)) => {
+                    if let Err(e) = pquote::export_fortune(&quotes, &path) {
+                        fail(format!("{}: {}", path, e));
                     }
-                    _ => println!("Sorry no quotes found by {}", o),
+                    println!("Wrote {} quotes to {} and {}.dat", quotes.len(), path, path);
                 }
-            } else {
-                print_quote(num, verbose, all, None)
+                (ExportKind::Fortune, None) => print!("{}", pquote::to_fortune(&quotes)),
+                (_, file) => export_page(&quotes, kind == ExportKind::Html, by, &title, file),
             }
         }
     }
 }
 
-fn print_quote(num: usize, verbose: bool, all: bool, list: Option<Vec<Quote>>) {
-    let quotes = if let Some(x) = list {
-        x
-    } else {
-        QUOTES.to_vec()
-    };
+// Print an error and give up
+fn fail(e: impl std::fmt::Display) -> ! {
+    eprintln!("{} {}", epaint(RED, "Error:"), e);
+    std::process::exit(1);
+}
 
-    if all {
-        for quote in quotes {
-            if verbose {
-                println!(
-                    "ID: {}\nQuote: {}\nAuthor: {}\nLink: {}\n",
-                    quote.id,
-                    Wrapper::with_termwidth().fill(quote.quote),
-                    quote.author,
-                    quote.permalink,
-                );
-            } else {
-                println!(
-                    "{}\n\n\t- {}",
-                    Wrapper::with_termwidth().fill(quote.quote),
-                    quote.author
-                );
-            }
+// How many quotes there are of each rating, origin and tag
+fn print_stats(quotes: &[Quote]) {
+    let mut authors: Vec<&str> = quotes.iter().map(|q| &*q.author.name).collect();
+    authors.sort_unstable();
+    authors.dedup();
+    println!("{} quotes by {} authors", quotes.len(), authors.len());
+
+    let row = |name: &str, count: usize| {
+        if count > 0 {
+            println!("    {:<20}{:>4}", name, count);
         }
-    } else if verbose {
-        println!(
-            "ID: {}\nQuote: {}\nAuthor: {}\nLink: {}\n",
-            quotes[num].id,
-            Wrapper::with_termwidth().fill(quotes[num].quote),
-            quotes[num].author,
-            quotes[num].permalink,
+    };
+    println!("\n{}", paint(YELLOW, "Rating:"));
+    for rating in Rating::ALL.iter() {
+        row(
+            &rating.to_string(),
+            quotes.iter().filter(|q| q.rating == *rating).count(),
+        );
+    }
+    println!("\n{}", paint(YELLOW, "Origin:"));
+    for kind in OriginKind::ALL.iter() {
+        row(
+            kind.name(),
+            quotes
+                .iter()
+                .filter(|q| q.permalink.kind() == *kind)
+                .count(),
+        );
+    }
+    println!("\n{}", paint(YELLOW, "Tag:"));
+    for tag in Tag::ALL.iter() {
+        row(
+            tag.name(),
+            quotes.iter().filter(|q| q.has_tag(*tag)).count(),
         );
+    }
+}
+
+// Write a page of quotes, to stdout without a file
+fn export_page(quotes: &[Quote], html: bool, by: GroupBy, title: &str, file: Option<String>) {
+    let page = if html {
+        pquote::html_page(quotes, title, by)
     } else {
-        println!(
+        pquote::markdown_page(quotes, title, by)
+    };
+    match file {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, page) {
+                fail(format!("{}: {}", path, e));
+            }
+            println!("Wrote {} quotes to {}", quotes.len(), path);
+        }
+        None => print!("{}", page),
+    }
+}
+
+// Paint the words of `text` that match a search for `query`, the rest in
+// the quote style of the theme
+fn highlight(text: &str, query: &str, theme: &Theme) -> String {
+    let hit = Style {
+        color: Some(YELLOW),
+        bold: true,
+        ..Style::default()
+    };
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for range in pquote::match_ranges(text, query) {
+        out.push_str(&theme.quote.paint(&text[last..range.start], depth()));
+        out.push_str(&hit.paint(&text[range.clone()], depth()));
+        last = range.end;
+    }
+    out.push_str(&theme.quote.paint(&text[last..], depth()));
+    out
+}
+
+// Colours of stdout, going by --color and the environment
+fn depth() -> ColorDepth {
+    depths().0
+}
+
+// Colours of stdout and stderr, as if --color=auto until it is parsed
+fn depths() -> (ColorDepth, ColorDepth) {
+    *DEPTH.get_or_init(|| {
+        let auto = |terminal| ColorChoice::Auto.depth(terminal);
+        (
+            auto(std::io::stdout().is_terminal()),
+            auto(std::io::stderr().is_terminal()),
+        )
+    })
+}
+
+// Text in a colour on stdout, e.g. the help
+fn paint(color: Color, text: &str) -> String {
+    let style = Style {
+        color: Some(color),
+        ..Style::default()
+    };
+    style.paint(text, depth())
+}
+
+// Text in a colour on stderr, e.g. "Error:"
+fn epaint(color: Color, text: &str) -> String {
+    let style = Style {
+        color: Some(color),
+        ..Style::default()
+    };
+    style.paint(text, depths().1)
+}
+
+// How print_quote lays out quotes
+enum OutputStyle {
+    Plain(Theme),
+    Verbose(Theme),
+    Format(Format),
+    Template(Template),
+    Frame(Frame),
+}
+
+fn print_quote(quote: &Quote, style: &OutputStyle) {
+    match style {
+        OutputStyle::Format(format) => print!("{}", format.quote(quote)),
+        OutputStyle::Verbose(theme) => println!(
+            "ID: {}\nQuote: {}\n{}\nLink: {}\n",
+            theme.id.paint(&quote.id.to_string(), depth()),
+            theme.quote.paint(
+                &Wrap::new(textwrap::termwidth()).fill(&quote.quote),
+                depth()
+            ),
+            theme.author.paint(&attribution(quote), depth()),
+            theme.link.paint(&quote.permalink.to_string(), depth()),
+        ),
+        OutputStyle::Template(template) => println!("{}", quote.render(template)),
+        OutputStyle::Frame(frame) => print!("{}", quote.framed(*frame, textwrap::termwidth(), 1)),
+        OutputStyle::Plain(theme) => println!(
             "{}\n\n\t- {}",
-            Wrapper::with_termwidth().fill(quotes[num].quote),
-            quotes[num].author
-        );
+            theme.quote.paint(
+                &Wrap::new(textwrap::termwidth()).fill(&quote.quote),
+                depth()
+            ),
+            theme.author.paint(&quote.author.name, depth())
+        ),
+    }
+}
+
+// Every quote, formats print them as one document
+fn print_list(quotes: &[Quote], style: &OutputStyle) {
+    match style {
+        OutputStyle::Format(format) => print!("{}", format.quotes(quotes)),
+        _ => {
+            for quote in quotes {
+                print_quote(quote, style);
+            }
+        }
+    }
+}
+
+// e.g. "— Robert C. Martin, *Clean Architecture* (2017)"
+fn attribution(quote: &Quote) -> String {
+    match quote.work {
+        Some(work) => match work.year {
+            Some(year) => format!("— {}, *{}* ({})", quote.author, work.title, year),
+            None => format!("— {}, *{}*", quote.author, work.title),
+        },
+        None => format!("— {}", quote.author),
     }
 }
@@ -0,0 +1,25 @@
+//! Compare the quotes of vim-startify to ours, to keep `data/quotes.toml` in sync
+//!
+//! ```sh
+//! cargo run --example startify -- vim-startify/autoload/startify/fortune.vim
+//! ```
+
+use std::process;
+
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: startify <path to fortune.vim>");
+            process::exit(1);
+        }
+    };
+    let quotes = std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|src| pquote::parse_startify(&src).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            eprintln!("{}: {}", path, e);
+            process::exit(1);
+        });
+    print!("{}", pquote::diff_startify(&quotes));
+}
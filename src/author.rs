@@ -0,0 +1,98 @@
+use crate::{authors, Quote, QUOTES};
+use std::borrow::Cow;
+
+/// Person a quote is attributed to, or people writing together
+///
+/// Every name an author has been quoted under is kept as an alias, so looking
+/// an author up by any of them finds all of their quotes. Quotes by several
+/// people, e.g. the authors of a book, are by an author with those people as
+/// its members, so looking up either of them finds those quotes too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Author {
+    /// Name their quotes are credited to, owned by authors that only quote
+    /// files loaded at runtime know
+    pub name: Cow<'static, str>,
+    pub aliases: &'static [&'static str],
+    /// People writing as this author, empty for a single person
+    pub members: &'static [&'static Author],
+    pub born: Option<i16>,
+    pub died: Option<i16>,
+    pub description: Option<&'static str>,
+}
+
+impl Author {
+    /// Canonical name followed by every alias
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(&*self.name).chain(self.aliases.iter().copied())
+    }
+
+    /// Check if a name refers to this author
+    ///
+    /// Matches the canonical name, any alias or just the surname, ignoring
+    /// case and punctuation, so "dijkstra" and "E.W. Dijkstra" both match
+    /// Edsger W. Dijkstra. Names of any member match too.
+    #[must_use]
+    pub fn matches(&self, query: &str) -> bool {
+        let normalized = normalize(query);
+        !normalized.is_empty()
+            && (self.names().any(|name| {
+                let name = normalize(name);
+                name == normalized || name.rsplit(' ').next() == Some(normalized.as_str())
+            }) || self.members.iter().any(|member| member.matches(query)))
+    }
+
+    /// Check if this author wrote a quote, alone or as a member of its author
+    #[must_use]
+    pub fn wrote(&self, quote: &Quote) -> bool {
+        quote.author.name == self.name || quote.author.members.iter().any(|m| m.name == self.name)
+    }
+
+    /// All quotes by this author, including those written with others
+    pub fn quotes(&self) -> impl Iterator<Item = &'static Quote> {
+        let author = self.clone();
+        QUOTES.iter().filter(move |q| author.wrote(q))
+    }
+
+    /// Find the author a name refers to, see [`Author::matches`]
+    ///
+    /// Full names and aliases win over surnames, so this returns `None` only
+    /// when no author matches at all.
+    #[must_use]
+    pub fn find(query: &str) -> Option<&'static Author> {
+        let normalized = normalize(query);
+        AUTHORS
+            .iter()
+            .find(|a| a.names().any(|name| normalize(name) == normalized))
+            .or_else(|| AUTHORS.iter().find(|a| a.matches(query)))
+    }
+}
+
+impl std::fmt::Display for Author {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Lowercase, with punctuation dropped and whitespace collapsed
+pub(crate) fn normalize(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '.')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '-')
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Get every quote by the authors a name refers to, see [`Author::matches`]
+pub fn by_author(query: &str) -> impl Iterator<Item = &'static Quote> {
+    let query = query.to_string();
+    QUOTES.iter().filter(move |q| q.author.matches(&query))
+}
+
+/// Every author in [`QUOTES`], sorted by name
+pub static AUTHORS: [Author; authors::LIST.len()] = authors::LIST;
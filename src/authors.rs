@@ -0,0 +1,8 @@
+//! Everyone quoted in [`QUOTES`](crate::QUOTES), see [`AUTHORS`](crate::AUTHORS) for a list
+//!
+//! Generated by `build.rs` from `data/authors.toml`.
+
+use crate::Author;
+use std::borrow::Cow;
+
+include!(concat!(env!("OUT_DIR"), "/authors.rs"));
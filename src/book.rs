@@ -0,0 +1,571 @@
+use crate::author::normalize;
+use crate::fortune;
+use crate::random::{bounded, choose, choose_seeded, days_since_epoch};
+use crate::search::{closest_author, rank_authors};
+use crate::toml::{self, Table, Value};
+use crate::{
+    authors, works, Author, AuthorMatch, Hit, Index, Origin, OriginKind, Quote, Rating, Tag,
+    UnknownIdError, AUTHORS, QUOTES,
+};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+
+/// Keys a `[[quote]]` of a quote file may have
+const KEYS: [&str; 7] = [
+    "id",
+    "author",
+    "work",
+    "quote",
+    "permalink",
+    "tags",
+    "rating",
+];
+
+/// Owned set of quotes: the built-in [`QUOTES`] plus your own
+///
+/// Extra quotes are loaded from TOML files like [`user_file`]:
+///
+/// ```toml
+/// # Built-in quotes to leave out
+/// disable = [31, 200]
+///
+/// [[quote]]
+/// id = 1001                  # optional, the next free id by default
+/// author = "Fred Brooks"     # known authors are matched by name or alias
+/// work = "The Mythical Man-Month: Essays on Software Engineering" # optional
+/// quote = "Plan to throw one away; you will, anyhow."
+/// permalink = "https://wiki.example.com/retro" # optional, the file by default
+/// tags = ["management"]      # optional
+/// rating = "safe"            # optional, "safe" by default
+/// ```
+///
+/// A quote with the id of a built-in one takes its place. A `work` has to
+/// be the full title of one of the built-in [`works`](crate::works), quote
+/// files can't add works of their own. A `permalink` to one of the sites
+/// built-in quotes come from gets that [`Origin`], so quotes written by
+/// [`Format::Toml`](crate::Format::Toml) read back as they were.
+///
+/// The book owns the text of loaded quotes, built-in ones keep borrowing
+/// theirs. With the `sfw` feature crude quotes are left out of files too.
+#[derive(Debug, Clone)]
+pub struct QuoteBook {
+    quotes: Vec<Quote>,
+}
+
+/// Error returned when a quote file can't be read or has a mistake in it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadError {
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) line: Option<usize>,
+    pub(crate) message: String,
+}
+
+impl LoadError {
+    /// File the error is in, if the quotes came from a file
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}: ", path.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Origin of a permalink, the site of built-in quotes it links to if any
+fn origin(url: &str) -> Origin {
+    let number = |prefix: &str| url.strip_prefix(prefix).and_then(|n| n.parse::<u32>().ok());
+    if let Some(n) = number("https://www.azquotes.com/quote/") {
+        return Origin::AZQuotesQuote(n);
+    }
+    if let Some(n) = number("http://quotes.stormconsultancy.co.uk/quotes/") {
+        if let Ok(n) = u8::try_from(n) {
+            return Origin::StormConsultancy(n);
+        }
+    }
+    if let Some(slug) = url.strip_prefix("https://www.azquotes.com/author/") {
+        // Only authors of built-in quotes have a slug to borrow
+        let known = QUOTES.iter().find_map(|q| match q.permalink {
+            Origin::AZQuotesAuthor(known) if known == slug => Some(known),
+            _ => None,
+        });
+        if let Some(slug) = known {
+            return Origin::AZQuotesAuthor(slug);
+        }
+    }
+    [Origin::GoodReads, Origin::JournalDev, Origin::VimStartify]
+        .iter()
+        .find(|origin| origin.to_string() == url)
+        .cloned()
+        .unwrap_or_else(|| Origin::User(Cow::Owned(url.to_string())))
+}
+
+/// Where `pq` looks for your own quotes
+///
+/// That is `$XDG_CONFIG_HOME/pquote/quotes.toml`, falling back to
+/// `~/.config/pquote/quotes.toml` (`%APPDATA%\pquote\quotes.toml` on Windows).
+/// Returns `None` if none of those variables are set.
+#[must_use]
+pub fn user_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("quotes.toml"))
+}
+
+/// Directory of `pq`'s own files, `pquote` in the user's config directory
+pub(crate) fn config_dir() -> Option<PathBuf> {
+    let env = |key| {
+        std::env::var_os(key)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let dir = if cfg!(windows) {
+        env("APPDATA")
+    } else {
+        env("XDG_CONFIG_HOME").or_else(|| env("HOME").map(|home| home.join(".config")))
+    };
+    dir.map(|dir| dir.join("pquote"))
+}
+
+impl Default for QuoteBook {
+    fn default() -> Self {
+        QuoteBook::new()
+    }
+}
+
+impl QuoteBook {
+    /// Book of the built-in quotes
+    #[must_use]
+    pub fn new() -> Self {
+        QuoteBook {
+            quotes: QUOTES.to_vec(),
+        }
+    }
+
+    /// Book of the built-in quotes plus the ones in [`user_file`], if it exists
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the file exists but can't be read or is invalid.
+    pub fn with_user_file() -> Result<Self, LoadError> {
+        let mut book = QuoteBook::new();
+        if let Some(path) = user_file().filter(|path| path.is_file()) {
+            book.load(path)?;
+        }
+        Ok(book)
+    }
+
+    /// Add the quotes of a file, see [`QuoteBook`] for its format
+    ///
+    /// Nothing is added if the file has any mistake in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the file can't be read or is invalid.
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        let at = |e: LoadError| LoadError {
+            path: Some(path.to_path_buf()),
+            ..e
+        };
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            at(LoadError {
+                path: None,
+                line: None,
+                message: e.to_string(),
+            })
+        })?;
+        self.load_from(&text, &path.display().to_string())
+            .map_err(at)
+    }
+
+    /// Add the quotes of a TOML string, see [`QuoteBook`] for its format
+    ///
+    /// Nothing is added if the string has any mistake in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the string is invalid.
+    pub fn load_str(&mut self, text: &str) -> Result<(), LoadError> {
+        self.load_from(text, "")
+    }
+
+    fn load_from(&mut self, text: &str, source: &str) -> Result<(), LoadError> {
+        let error = |message: String| LoadError {
+            path: None,
+            line: None,
+            message,
+        };
+        let mut root = toml::parse(text).map_err(|e| LoadError {
+            path: None,
+            line: Some(e.line),
+            message: e.message,
+        })?;
+
+        let mut book = self.clone();
+        match root.remove("disable") {
+            None => {}
+            Some(Value::Array(ids)) => {
+                for id in ids {
+                    // Ids without a quote are fine, e.g. crude ones with the `sfw` feature
+                    match id {
+                        Value::Integer(id) if u32::try_from(id).is_ok() => {
+                            book.disable(id as u32);
+                        }
+                        _ => return Err(error("'disable' should be a list of ids".to_string())),
+                    }
+                }
+            }
+            Some(_) => return Err(error("'disable' should be a list of ids".to_string())),
+        }
+        let quotes = match root.remove("quote") {
+            None => Vec::new(),
+            Some(Value::Array(quotes)) => quotes,
+            Some(_) => return Err(error("'quote' should be [[quote]] tables".to_string())),
+        };
+        if let Some(key) = root.keys().next() {
+            return Err(error(format!("unknown key '{}'", key)));
+        }
+        let mut ids = Vec::with_capacity(quotes.len());
+        for (i, quote) in quotes.into_iter().enumerate() {
+            let quote = match quote {
+                Value::Table(table) => book.parse_quote(&table, source),
+                other => Err(format!("expected a table, found {}", other.type_name())),
+            }
+            .and_then(|quote| {
+                if ids.contains(&quote.id) {
+                    Err(format!("id {} is used twice", quote.id))
+                } else {
+                    Ok(quote)
+                }
+            })
+            .map_err(|e| error(format!("[[quote]] number {}: {}", i + 1, e)))?;
+            ids.push(quote.id);
+            book.disable(quote.id);
+            if !(cfg!(feature = "sfw") && quote.rating == Rating::Crude) {
+                book.quotes.push(quote);
+            }
+        }
+        *self = book;
+        Ok(())
+    }
+
+    /// Add the fortunes of a fortune(6) text file, see [`export_fortune`](crate::export_fortune)
+    ///
+    /// A last line like `-- Author` gives the author of a fortune, those
+    /// without one are by [`authors::UNKNOWN`](crate::authors::UNKNOWN).
+    /// Fortunes are not tagged and get the next free ids. Fortune files say
+    /// nothing about how suitable their fortunes are, so they all get the
+    /// `rating` given, e.g. [`Rating::Controversial`] unless you know them.
+    /// With the `sfw` feature nothing is added if that is [`Rating::Crude`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the file can't be read.
+    pub fn load_fortune(
+        &mut self,
+        path: impl AsRef<Path>,
+        rating: Rating,
+    ) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| LoadError {
+            path: Some(path.to_path_buf()),
+            line: None,
+            message: e.to_string(),
+        })?;
+        self.load_fortune_str(&text, &path.display().to_string(), rating);
+        Ok(())
+    }
+
+    /// Add the fortunes of a fortune(6) text, see [`QuoteBook::load_fortune`]
+    ///
+    /// `source` is what the permalink of the new quotes shows, e.g. a path.
+    pub fn load_fortune_str(&mut self, text: &str, source: &str, rating: Rating) {
+        if cfg!(feature = "sfw") && rating == Rating::Crude {
+            return;
+        }
+        for fortune in fortune::parse(text) {
+            let author = match fortune.author {
+                Some(name) => self.author(&name),
+                None => authors::UNKNOWN,
+            };
+            self.quotes.push(Quote {
+                author,
+                work: None,
+                id: self.next_id(),
+                quote: Cow::Owned(fortune.quote),
+                permalink: Origin::User(Cow::Owned(source.to_string())),
+                tags: Cow::Borrowed(&[]),
+                rating,
+            });
+        }
+    }
+
+    /// Id after the highest one in the book
+    fn next_id(&self) -> u32 {
+        self.quotes.iter().map(|q| q.id).max().unwrap_or(0) + 1
+    }
+
+    fn parse_quote(&self, table: &Table, source: &str) -> Result<Quote, String> {
+        if let Some(key) = table.keys().find(|key| !KEYS.contains(&key.as_str())) {
+            return Err(format!("unknown key '{}'", key));
+        }
+        let string = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(Value::String(s)) if !s.trim().is_empty() => Ok(Some(s.trim())),
+            Some(_) => Err(format!("'{}' should be a non-empty string", key)),
+        };
+
+        let id = match table.get("id") {
+            None => self.next_id(),
+            Some(Value::Integer(id)) if *id > 0 && *id <= i64::from(u32::MAX) => *id as u32,
+            Some(_) => return Err("'id' should be a positive number".to_string()),
+        };
+        let author = match string("author")? {
+            Some(name) => self.author(name),
+            None => return Err("'author' is missing".to_string()),
+        };
+        let work = match string("work")? {
+            None => None,
+            Some(title) => Some(
+                works::LIST
+                    .iter()
+                    .copied()
+                    .find(|work| work.title.eq_ignore_ascii_case(title))
+                    .ok_or_else(|| format!("unknown work '{}'", title))?,
+            ),
+        };
+        let quote = match string("quote")? {
+            Some(quote) => quote.to_string(),
+            None => return Err("'quote' is missing".to_string()),
+        };
+        let permalink = match string("permalink")? {
+            Some(url) => origin(url),
+            None => Origin::User(Cow::Owned(source.to_string())),
+        };
+        let tags = match table.get("tags") {
+            None => Vec::new(),
+            Some(Value::Array(tags)) => tags
+                .iter()
+                .map(|tag| match tag {
+                    Value::String(tag) => tag.parse::<Tag>().map_err(|e| e.to_string()),
+                    _ => Err("'tags' should be a list of tag names".to_string()),
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err("'tags' should be a list of tag names".to_string()),
+        };
+        let rating = match string("rating")? {
+            None => Rating::Safe,
+            Some(name) => Rating::ALL
+                .iter()
+                .copied()
+                .find(|rating| rating.to_string().eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    format!(
+                        "unknown rating '{}', expected one of: safe, controversial, crude",
+                        name
+                    )
+                })?,
+        };
+        Ok(Quote {
+            author,
+            work,
+            id,
+            quote: Cow::Owned(quote),
+            permalink,
+            tags: Cow::Owned(tags),
+            rating,
+        })
+    }
+
+    /// Author with a name, reusing known ones so their quotes stay together
+    fn author(&self, name: &str) -> Author {
+        author_named(name, self.quotes.iter().map(|q| &q.author))
+    }
+
+    /// Add a quote, failing if its id is already used
+    ///
+    /// # Errors
+    ///
+    /// Returns the quote back if the book already has one with its id.
+    #[allow(clippy::result_large_err)]
+    pub fn add(&mut self, quote: Quote) -> Result<(), Quote> {
+        if self.by_id(quote.id).is_some() {
+            Err(quote)
+        } else {
+            self.quotes.push(quote);
+            Ok(())
+        }
+    }
+
+    /// Leave out the quote with an id, returns whether there was one
+    pub fn disable(&mut self, id: u32) -> bool {
+        let len = self.quotes.len();
+        self.quotes.retain(|q| q.id != id);
+        self.quotes.len() != len
+    }
+
+    /// Every quote in the book, built-in ones first
+    #[must_use]
+    pub fn quotes(&self) -> &[Quote] {
+        &self.quotes
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.quotes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.quotes.is_empty()
+    }
+
+    /// Get the quote with the given id, like [`by_id`](crate::by_id)
+    #[must_use]
+    pub fn by_id(&self, id: u32) -> Option<&Quote> {
+        self.quotes.iter().find(|q| q.id == id)
+    }
+
+    /// Get the quote with the given id, like [`try_by_id`](crate::try_by_id)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownIdError`] if no quote has the given id.
+    pub fn try_by_id(&self, id: u32) -> Result<&Quote, UnknownIdError> {
+        self.by_id(id).ok_or(UnknownIdError { id })
+    }
+
+    /// Get a random quote, like [`random`](crate::random)
+    ///
+    /// Returns `None` if every quote was disabled.
+    #[must_use]
+    pub fn random(&self) -> Option<&Quote> {
+        choose(&self.quotes)
+    }
+
+    /// Get a random quote using your own source of random `u64`s, like
+    /// [`random_with`](crate::random_with)
+    pub fn random_with(&self, next: &mut impl FnMut() -> u64) -> Option<&Quote> {
+        if self.quotes.is_empty() {
+            None
+        } else {
+            Some(&self.quotes[bounded(next(), self.quotes.len())])
+        }
+    }
+
+    /// Get the quote for a seed, like [`seeded`](crate::seeded)
+    #[must_use]
+    pub fn seeded(&self, seed: u64) -> Option<&Quote> {
+        choose_seeded(&self.quotes, seed)
+    }
+
+    /// Get the quote of the day for today's date in UTC, like [`daily`](crate::daily)
+    #[must_use]
+    pub fn daily(&self) -> Option<&Quote> {
+        self.seeded(days_since_epoch())
+    }
+
+    /// Get every quote by the authors a name refers to, like [`by_author`](crate::by_author)
+    pub fn by_author<'a>(&'a self, query: &'a str) -> impl Iterator<Item = &'a Quote> {
+        self.quotes.iter().filter(move |q| q.author.matches(query))
+    }
+
+    /// Rank the authors of the book against a query, like [`search_authors`](crate::search_authors)
+    #[must_use]
+    pub fn search_authors(&self, query: &str) -> Vec<(&Author, AuthorMatch)> {
+        rank_authors(self.authors().into_iter(), query, |a| self.count_by(a))
+    }
+
+    /// Get the quotes of every author matching a query, like [`search_author`](crate::search_author)
+    #[must_use]
+    pub fn search_author(&self, query: &str) -> Vec<&Quote> {
+        let mut found: Vec<&Quote> = Vec::new();
+        for (author, _) in self.search_authors(query) {
+            for quote in self.quotes.iter().filter(|q| author.wrote(q)) {
+                if !found.iter().any(|q| q.id == quote.id) {
+                    found.push(quote);
+                }
+            }
+        }
+        found
+    }
+
+    /// Suggest the author a query most likely meant, like [`suggest_author`](crate::suggest_author)
+    #[must_use]
+    pub fn suggest_author(&self, query: &str) -> Option<&Author> {
+        closest_author(self.authors().into_iter(), query, |a| self.count_by(a))
+    }
+
+    /// Every author in the book and their members, once each
+    fn authors(&self) -> Vec<&Author> {
+        let mut authors: Vec<&Author> = Vec::new();
+        for quote in &self.quotes {
+            for author in std::iter::once(&quote.author).chain(quote.author.members.iter().copied())
+            {
+                if !authors.iter().any(|a| a.name == author.name) {
+                    authors.push(author);
+                }
+            }
+        }
+        authors
+    }
+
+    fn count_by(&self, author: &Author) -> usize {
+        self.quotes.iter().filter(|q| author.wrote(q)).count()
+    }
+
+    /// All quotes with a tag, like [`Tag::quotes`]
+    pub fn tagged(&self, tag: Tag) -> impl Iterator<Item = &Quote> {
+        self.quotes.iter().filter(move |q| q.has_tag(tag))
+    }
+
+    /// All quotes rated this or milder, like [`Rating::at_most`]
+    pub fn rated_at_most(&self, rating: Rating) -> impl Iterator<Item = &Quote> {
+        self.quotes.iter().filter(move |q| q.rating <= rating)
+    }
+
+    /// All quotes from an origin, like [`OriginKind::quotes`]
+    pub fn from_origin(&self, kind: OriginKind) -> impl Iterator<Item = &Quote> {
+        self.quotes
+            .iter()
+            .filter(move |q| q.permalink.kind() == kind)
+    }
+
+    /// Search the text of the quotes, like [`search`](crate::search)
+    ///
+    /// Indexes the book on every call, keep an [`Index`] of
+    /// [`QuoteBook::quotes`] around to search it repeatedly.
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<Hit<'_>> {
+        Index::new(&self.quotes).search(query)
+    }
+}
+
+/// Author in [`AUTHORS`] or `others` with a name or alias, or a new one
+pub(crate) fn author_named<'a>(name: &str, mut others: impl Iterator<Item = &'a Author>) -> Author {
+    let normalized = normalize(name);
+    let same = |author: &&Author| author.names().any(|n| normalize(n) == normalized);
+    AUTHORS
+        .iter()
+        .find(same)
+        .or_else(|| others.find(same))
+        .cloned()
+        .unwrap_or_else(|| Author {
+            name: Cow::Owned(name.to_string()),
+            aliases: &[],
+            members: &[],
+            born: None,
+            died: None,
+            description: None,
+        })
+}
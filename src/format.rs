@@ -0,0 +1,315 @@
+use crate::Quote;
+use std::fmt::Write;
+
+/// Machine-readable format to write quotes in
+///
+/// Every format has the same fields, in this order: `id`, `author`, `work`,
+/// `year`, `quote`, `origin` (its [`OriginKind`](crate::OriginKind) name),
+/// `permalink`, `tags` and `rating`. A missing work or year is `null` in
+/// JSON and YAML and empty in CSV and TSV. TOML is the exception, see
+/// [`Format::Toml`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// A JSON object, or an array of them for several quotes
+    Json,
+    /// One JSON object per line
+    Jsonl,
+    /// A YAML mapping, or a sequence of them for several quotes
+    Yaml,
+    /// RFC 4180 CSV with a header row, tags separated by commas
+    Csv,
+    /// Tab-separated values with a header row, where tabs, newlines and
+    /// backslashes in a field are written as `\t`, `\n` and `\\`
+    Tsv,
+    /// A `[[quote]]` table per quote, a quote file that
+    /// [`QuoteBook::load`](crate::QuoteBook::load) reads back
+    ///
+    /// Only has the keys quote files have, so there is no `year` or `origin`,
+    /// and `work` is the title alone, left out when there is none. The origin
+    /// follows from the permalink when the file is loaded.
+    Toml,
+}
+
+/// Names of the fields, in the order they are written
+const FIELDS: [&str; 9] = [
+    "id",
+    "author",
+    "work",
+    "year",
+    "quote",
+    "origin",
+    "permalink",
+    "tags",
+    "rating",
+];
+
+impl Format {
+    /// Every format, in alphabetical order
+    pub const ALL: [Format; 6] = [
+        Format::Csv,
+        Format::Json,
+        Format::Jsonl,
+        Format::Toml,
+        Format::Tsv,
+        Format::Yaml,
+    ];
+
+    /// Lowercase name of the format, as accepted by [`str::parse`]
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Jsonl => "jsonl",
+            Format::Yaml => "yaml",
+            Format::Csv => "csv",
+            Format::Tsv => "tsv",
+            Format::Toml => "toml",
+        }
+    }
+
+    /// Write a single quote, ending in a newline
+    #[must_use]
+    pub fn quote(self, quote: &Quote) -> String {
+        match self {
+            Format::Json => {
+                let mut out = json_object(quote, "  ", "");
+                out.push('\n');
+                out
+            }
+            Format::Yaml => yaml_mapping(quote, ""),
+            _ => self.quotes(std::slice::from_ref(quote)),
+        }
+    }
+
+    /// Write several quotes, ending in a newline
+    #[must_use]
+    pub fn quotes(self, quotes: &[Quote]) -> String {
+        let mut out = String::new();
+        match self {
+            Format::Json => {
+                out.push('[');
+                for (i, quote) in quotes.iter().enumerate() {
+                    out.push_str(if i > 0 { ",\n  " } else { "\n  " });
+                    out.push_str(&json_object(quote, "    ", "  "));
+                }
+                out.push_str(if quotes.is_empty() { "]\n" } else { "\n]\n" });
+            }
+            Format::Jsonl => {
+                for quote in quotes {
+                    out.push_str(&json_object(quote, "", ""));
+                    out.push('\n');
+                }
+            }
+            Format::Yaml => {
+                if quotes.is_empty() {
+                    out.push_str("[]\n");
+                }
+                for quote in quotes {
+                    out.push_str("- ");
+                    out.push_str(&yaml_mapping(quote, "  ")[2..]);
+                }
+            }
+            Format::Csv | Format::Tsv => {
+                let (separator, escape): (&str, fn(&str) -> String) = match self {
+                    Format::Csv => (",", csv_field),
+                    _ => ("\t", tsv_field),
+                };
+                out.push_str(&FIELDS.join(separator));
+                out.push('\n');
+                for quote in quotes {
+                    let work = quote.work.map(|w| w.title).unwrap_or_default();
+                    let year = quote.work.and_then(|w| w.year);
+                    let fields = [
+                        quote.id.to_string(),
+                        escape(&quote.author.name),
+                        escape(work),
+                        year.map(|y| y.to_string()).unwrap_or_default(),
+                        escape(&quote.quote),
+                        quote.permalink.kind().name().to_string(),
+                        escape(&quote.permalink.to_string()),
+                        escape(&tag_names(quote).join(",")),
+                        quote.rating.to_string(),
+                    ];
+                    out.push_str(&fields.join(separator));
+                    out.push('\n');
+                }
+            }
+            Format::Toml => {
+                for (i, quote) in quotes.iter().enumerate() {
+                    if i > 0 {
+                        out.push('\n');
+                    }
+                    toml_table(&mut out, quote);
+                }
+            }
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when parsing a [`Format`] from an unknown name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFormatError {
+    name: String,
+}
+
+impl std::fmt::Display for ParseFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "unknown format '{}', expected one of: ", self.name)?;
+        for (i, format) in Format::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(format.name())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseFormatError {}
+
+impl std::str::FromStr for Format {
+    type Err = ParseFormatError;
+
+    /// Parse a format from its name, ignoring case, `yml` and `ndjson` work too
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        match name.as_str() {
+            "yml" => Some(Format::Yaml),
+            "ndjson" => Some(Format::Jsonl),
+            _ => Format::ALL
+                .iter()
+                .copied()
+                .find(|format| format.name() == name),
+        }
+        .ok_or_else(|| ParseFormatError {
+            name: s.trim().to_string(),
+        })
+    }
+}
+
+fn tag_names(quote: &Quote) -> Vec<&'static str> {
+    quote.tags.iter().map(|t| t.name()).collect()
+}
+
+/// JSON object of a quote, on one line if `indent` is empty
+fn json_object(quote: &Quote, indent: &str, close: &str) -> String {
+    let tags: Vec<String> = tag_names(quote).iter().map(|t| quoted(t)).collect();
+    let values = [
+        quote.id.to_string(),
+        quoted(&quote.author.name),
+        quote.work.map_or("null".to_string(), |w| quoted(w.title)),
+        quote
+            .work
+            .and_then(|w| w.year)
+            .map_or("null".to_string(), |y| y.to_string()),
+        quoted(&quote.quote),
+        quoted(quote.permalink.kind().name()),
+        quoted(&quote.permalink.to_string()),
+        format!("[{}]", tags.join(",")),
+        quoted(&quote.rating.to_string()),
+    ];
+    let (open, separator, colon, end) = if indent.is_empty() {
+        ("{", ",", ":", "}".to_string())
+    } else {
+        ("{\n", ",\n", ": ", format!("\n{}}}", close))
+    };
+    let mut out = open.to_string();
+    for (i, (field, value)) in FIELDS.iter().zip(&values).enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        let _ = write!(out, "{}\"{}\"{}{}", indent, field, colon, value);
+    }
+    out + &end
+}
+
+/// YAML mapping of a quote, every line starting with `indent`
+fn yaml_mapping(quote: &Quote, indent: &str) -> String {
+    let tags: Vec<String> = tag_names(quote).iter().map(|t| quoted(t)).collect();
+    let values = [
+        quote.id.to_string(),
+        quoted(&quote.author.name),
+        quote.work.map_or("null".to_string(), |w| quoted(w.title)),
+        quote
+            .work
+            .and_then(|w| w.year)
+            .map_or("null".to_string(), |y| y.to_string()),
+        quoted(&quote.quote),
+        quote.permalink.kind().name().to_string(),
+        quoted(&quote.permalink.to_string()),
+        format!("[{}]", tags.join(", ")),
+        quote.rating.to_string(),
+    ];
+    let mut out = String::new();
+    for (field, value) in FIELDS.iter().zip(&values) {
+        let _ = writeln!(out, "{}{}: {}", indent, field, value);
+    }
+    out
+}
+
+/// `[[quote]]` table of a quote, with the keys of a quote file
+fn toml_table(out: &mut String, quote: &Quote) {
+    let tags: Vec<String> = tag_names(quote).iter().map(|t| quoted(t)).collect();
+    out.push_str("[[quote]]\n");
+    let _ = writeln!(out, "id = {}", quote.id);
+    let _ = writeln!(out, "author = {}", quoted(&quote.author.name));
+    if let Some(work) = quote.work {
+        let _ = writeln!(out, "work = {}", quoted(work.title));
+    }
+    let _ = writeln!(out, "quote = {}", quoted(&quote.quote));
+    let _ = writeln!(out, "permalink = {}", quoted(&quote.permalink.to_string()));
+    let _ = writeln!(out, "tags = [{}]", tags.join(", "));
+    let _ = writeln!(out, "rating = {}", quoted(&quote.rating.to_string()));
+}
+
+/// Double-quoted string with backslash escapes, valid in JSON, YAML and TOML
+fn quoted(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// CSV field, quoted if it has a separator, quote or line break in it
+fn csv_field(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// TSV field, with tabs, line breaks and backslashes escaped
+fn tsv_field(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,172 @@
+use crate::layout::Wrap;
+use crate::Quote;
+use std::io;
+use std::path::Path;
+
+/// Column fortunes are wrapped at
+const WIDTH: usize = 72;
+
+/// Version of the `strfile` index format written by [`fortune_index`]
+const STRFILE_VERSION: u32 = 2;
+
+/// Size of the header of a `strfile` index
+const HEADER_LEN: usize = 24;
+
+/// Format quotes as a fortune(6) text file
+///
+/// Each quote is wrapped at 72 columns and followed by its author and a `%`
+/// line, the way `fortune` expects. Lines of the quote start at the left
+/// edge, what wrapping adds is indented two spaces, so reading the file back
+/// keeps the line breaks of the quote.
+#[must_use]
+pub fn to_fortune(quotes: &[Quote]) -> String {
+    let wrap = Wrap::new(WIDTH).hanging(2);
+    let mut out = String::new();
+    for quote in quotes {
+        for line in quote.quote.lines() {
+            for line in wrap.lines(line.trim()) {
+                out.push_str(&line);
+                out.push('\n');
+            }
+        }
+        out.push_str("\t\t-- ");
+        out.push_str(&quote.author.name);
+        out.push_str("\n%\n");
+    }
+    out
+}
+
+/// Build the binary index `strfile` makes for a fortune text file
+///
+/// The index has the same layout as `strfile` writes it: a header of big
+/// endian `u32`s (version, number of fortunes, longest and shortest length,
+/// flags and the delimiter), followed by the offset of every fortune and of
+/// the end of the file.
+#[must_use]
+pub fn fortune_index(text: &str) -> Vec<u8> {
+    let mut offsets = vec![0_u32];
+    let (mut longest, mut shortest) = (0, u32::MAX);
+    let mut last = 0;
+    let mut pos = 0;
+    // `None` marks the end of the file, which ends the last fortune too
+    let lines = text
+        .split_inclusive('\n')
+        .map(Some)
+        .chain(std::iter::once(None));
+    for line in lines {
+        let line_len = line.map_or(0, str::len);
+        pos += line_len;
+        if line.map_or(true, |line| line == "%\n") {
+            let length = (pos - last - line_len) as u32;
+            last = pos;
+            if length > 0 {
+                offsets.push(pos as u32);
+                longest = longest.max(length);
+                shortest = shortest.min(length);
+            }
+        }
+    }
+
+    let count = offsets.len() as u32 - 1;
+    if count == 0 {
+        shortest = 0;
+    }
+    let mut index = Vec::with_capacity(HEADER_LEN + offsets.len() * 4);
+    for field in [STRFILE_VERSION, count, longest, shortest, 0] {
+        index.extend_from_slice(&field.to_be_bytes());
+    }
+    index.extend_from_slice(&[b'%', 0, 0, 0]);
+    for offset in offsets {
+        index.extend_from_slice(&offset.to_be_bytes());
+    }
+    index
+}
+
+/// Write quotes to a fortune file and its `.dat` index, ready for `fortune path`
+///
+/// # Errors
+///
+/// Returns any error writing either file.
+pub fn export_fortune(quotes: &[Quote], path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    let text = to_fortune(quotes);
+    let mut dat = path.as_os_str().to_os_string();
+    dat.push(".dat");
+    std::fs::write(path, &text)?;
+    std::fs::write(dat, fortune_index(&text))
+}
+
+/// Fortune read from a fortune file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Fortune {
+    pub author: Option<String>,
+    pub quote: String,
+}
+
+/// Split a fortune file into its fortunes
+///
+/// A last line like `-- Author` is taken as the author, anything after a
+/// comma in it (usually the work) is dropped. Indented lines continue the
+/// line before them, like the ones [`to_fortune`] wraps, every other line
+/// break is kept.
+pub(crate) fn parse(text: &str) -> Vec<Fortune> {
+    let mut fortunes = Vec::new();
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.lines().chain(std::iter::once("%")) {
+        if line != "%" {
+            lines.push(line);
+            continue;
+        }
+        while lines.last().map_or(false, |line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        // An attribution needs some text before it
+        let author = match lines.split_last() {
+            Some((last, text)) if text.iter().any(|line| !line.trim().is_empty()) => {
+                attribution(last)
+            }
+            _ => None,
+        };
+        if author.is_some() {
+            lines.pop();
+        }
+
+        let mut quote = String::new();
+        // Whether the last line read has text to continue
+        let mut open = false;
+        for line in lines.drain(..) {
+            let text = line.trim();
+            if quote.is_empty() && text.is_empty() {
+                continue;
+            }
+            if open && line.starts_with(char::is_whitespace) {
+                quote.push(' ');
+            } else if !quote.is_empty() {
+                quote.push('\n');
+            }
+            quote.push_str(text);
+            open = !text.is_empty();
+        }
+        // Blank lines between the text and the author
+        quote.truncate(quote.trim_end().len());
+        if !quote.is_empty() {
+            fortunes.push(Fortune { author, quote });
+        }
+    }
+    fortunes
+}
+
+/// Author named by a `-- Author, Work` line
+fn attribution(line: &str) -> Option<String> {
+    let line = line.trim();
+    let (dash, rest) = ["--", "\u{2014}", "\u{2013}", "-"]
+        .iter()
+        .find_map(|dash| line.strip_prefix(dash).map(|rest| (*dash, rest)))?;
+    let name = rest.split(',').next().unwrap_or_default().trim();
+    // A lone hyphen right before a word is more likely part of the text
+    if name.is_empty() || (dash == "-" && !rest.starts_with(char::is_whitespace)) {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
@@ -0,0 +1,273 @@
+use crate::layout::{attribution, display_width, wrap, Wrap};
+use crate::Quote;
+use std::fmt::Write;
+
+/// Frame drawn around a quote by [`Quote::framed`], e.g. for a MOTD
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Frame {
+    /// Box of single lines, `┌─┐`
+    Single,
+    /// Box of double lines, `╔═╗`
+    Double,
+    /// Box of single lines with round corners, `╭─╮`
+    Rounded,
+    /// Box of `+`, `-` and `|` for terminals without box-drawing characters
+    ///
+    /// Only the frame and attribution are ASCII, the quote and names keep
+    /// their own characters, e.g. `é` or `—`.
+    Ascii,
+    /// Cowsay speech bubble with the cow saying the quote, drawn in ASCII
+    Bubble,
+    /// Lines centred between two rules as wide as the frame, drawn in ASCII
+    Banner,
+}
+
+/// Corners, horizontal and vertical line of a box
+struct BoxChars {
+    top_left: char,
+    top_right: char,
+    bottom_left: char,
+    bottom_right: char,
+    horizontal: char,
+    vertical: char,
+}
+
+const COW: &str = r"        \   ^__^
+         \  (oo)\_______
+            (__)\       )\/\
+                ||----w |
+                ||     ||";
+
+impl Frame {
+    /// Every frame, in alphabetical order
+    pub const ALL: [Frame; 6] = [
+        Frame::Ascii,
+        Frame::Banner,
+        Frame::Bubble,
+        Frame::Double,
+        Frame::Rounded,
+        Frame::Single,
+    ];
+
+    /// Lowercase name of the frame, as accepted by [`str::parse`]
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Frame::Single => "single",
+            Frame::Double => "double",
+            Frame::Rounded => "rounded",
+            Frame::Ascii => "ascii",
+            Frame::Bubble => "bubble",
+            Frame::Banner => "banner",
+        }
+    }
+
+    fn box_chars(self) -> Option<BoxChars> {
+        let chars = |corners: [char; 4], horizontal, vertical| {
+            Some(BoxChars {
+                top_left: corners[0],
+                top_right: corners[1],
+                bottom_left: corners[2],
+                bottom_right: corners[3],
+                horizontal,
+                vertical,
+            })
+        };
+        match self {
+            Frame::Single => chars(['┌', '┐', '└', '┘'], '─', '│'),
+            Frame::Double => chars(['╔', '╗', '╚', '╝'], '═', '║'),
+            Frame::Rounded => chars(['╭', '╮', '╰', '╯'], '─', '│'),
+            Frame::Ascii => chars(['+', '+', '+', '+'], '-', '|'),
+            Frame::Bubble | Frame::Banner => None,
+        }
+    }
+
+    /// Whether the frame is drawn in ASCII, so the dash of the attribution is
+    /// too, the quote is left as it is
+    fn is_ascii(self) -> bool {
+        matches!(self, Frame::Ascii | Frame::Bubble | Frame::Banner)
+    }
+}
+
+/// Draw a [`Frame`] around a quote
+impl Quote {
+    /// The quote wrapped to fit in `max_width` columns, frame included, with
+    /// its attribution right-aligned below it like [`Quote::wrapped`]
+    ///
+    /// An attribution too wide for the frame is wrapped on the left instead,
+    /// its lines after the first hanging under the name.
+    ///
+    /// `padding` is the number of spaces between the frame and the text on
+    /// either side. Boxes and bubbles shrink to fit short quotes, banners are
+    /// always `max_width` wide. Lines of multi-line quotes are kept apart.
+    ///
+    /// ```
+    /// use pquote::{display_width, Frame};
+    ///
+    /// let q = pquote::by_id(44).unwrap();
+    /// let framed = q.framed(Frame::Rounded, 40, 1);
+    /// assert!(framed.starts_with('╭'));
+    /// assert!(framed.lines().all(|line| display_width(line) <= 40));
+    /// ```
+    #[must_use]
+    pub fn framed(&self, frame: Frame, max_width: usize, padding: usize) -> String {
+        // Border and padding on either side, banners have no border
+        let border = if frame == Frame::Banner { 0 } else { 1 };
+        let text_width = max_width.saturating_sub(2 * (border + padding)).max(1);
+        let mut lines = wrap(&self.quote, text_width);
+        let dash = if frame.is_ascii() { "--" } else { "\u{2014}" };
+        let attribution = attribution(self, dash);
+        let (byline, right) = if display_width(&attribution) <= text_width {
+            (vec![attribution], true)
+        } else {
+            // Under the name, past the dash
+            let hanging = display_width(dash) + 1;
+            (
+                Wrap::new(text_width).hanging(hanging).lines(&attribution),
+                false,
+            )
+        };
+
+        // Width of the text in boxes and bubbles
+        let inner = lines
+            .iter()
+            .chain(&byline)
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0)
+            .min(text_width);
+        let space = |n: usize| " ".repeat(n);
+        let pad = space(padding);
+        let mut out = String::new();
+
+        if let Some(chars) = frame.box_chars() {
+            let rule = chars.horizontal.to_string().repeat(inner + 2 * padding);
+            let mut row = |text: &str, right: bool| {
+                let fill = space(inner.saturating_sub(display_width(text)));
+                let (left, right) = if right {
+                    (fill.as_str(), "")
+                } else {
+                    ("", fill.as_str())
+                };
+                let _ = writeln!(
+                    out,
+                    "{}{}{}{}{}{}{}",
+                    chars.vertical, pad, left, text, right, pad, chars.vertical
+                );
+            };
+            for line in &lines {
+                row(line, false);
+            }
+            row("", false);
+            for line in &byline {
+                row(line, right);
+            }
+            return format!(
+                "{}{}{}\n{}{}{}{}\n",
+                chars.top_left,
+                rule,
+                chars.top_right,
+                out,
+                chars.bottom_left,
+                rule,
+                chars.bottom_right
+            );
+        }
+
+        match frame {
+            Frame::Bubble => {
+                lines.push(String::new());
+                for line in byline {
+                    let fill = if right {
+                        space(inner.saturating_sub(display_width(&line)))
+                    } else {
+                        String::new()
+                    };
+                    lines.push(fill + &line);
+                }
+                let last = lines.len() - 1;
+                let _ = writeln!(out, " {}", "_".repeat(inner + 2 * padding));
+                for (i, line) in lines.iter().enumerate() {
+                    // Like cowsay, there are always several lines with the author
+                    let (left, right) = match i {
+                        0 => ('/', '\\'),
+                        _ if i == last => ('\\', '/'),
+                        _ => ('|', '|'),
+                    };
+                    let fill = space(inner.saturating_sub(display_width(line)));
+                    let _ = writeln!(out, "{}{}{}{}{}{}", left, pad, line, fill, pad, right);
+                }
+                let _ = writeln!(out, " {}", "-".repeat(inner + 2 * padding));
+                out.push_str(COW);
+                out.push('\n');
+            }
+            _ => {
+                let rule = "=".repeat(max_width.max(1));
+                let centred = |text: &str| {
+                    let margin = max_width.saturating_sub(display_width(text)) / 2;
+                    format!("{}{}\n", space(margin), text)
+                };
+                out.push_str(&rule);
+                out.push('\n');
+                for line in &lines {
+                    out.push_str(&centred(line));
+                }
+                out.push('\n');
+                for line in &byline {
+                    out.push_str(&centred(line));
+                }
+                out.push_str(&rule);
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when parsing a [`Frame`] from an unknown name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFrameError {
+    name: String,
+}
+
+impl std::fmt::Display for ParseFrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "unknown frame '{}', expected one of: ", self.name)?;
+        for (i, frame) in Frame::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(frame.name())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseFrameError {}
+
+impl std::str::FromStr for Frame {
+    type Err = ParseFrameError;
+
+    /// Parse a frame from its name, ignoring case, `box` is [`Frame::Single`]
+    /// and `cow` and `cowsay` are [`Frame::Bubble`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        match name.as_str() {
+            "box" => Some(Frame::Single),
+            "cow" | "cowsay" => Some(Frame::Bubble),
+            _ => Frame::ALL
+                .iter()
+                .copied()
+                .find(|frame| frame.name() == name),
+        }
+        .ok_or_else(|| ParseFrameError {
+            name: s.trim().to_string(),
+        })
+    }
+}
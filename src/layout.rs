@@ -0,0 +1,374 @@
+use crate::Quote;
+use std::fmt::Write;
+
+/// Zero width joiner, the characters it joins are drawn as one
+const ZWJ: char = '\u{200D}';
+
+/// Characters that take up no column, like combining accents
+const ZERO_WIDTH: [(u32, u32); 9] = [
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x2028, 0x202E),
+    (0x2060, 0x2064),
+    (0x20D0, 0x20FF),
+    (0xFE00, 0xFE0F),
+];
+
+/// Characters that take up two columns: East Asian wide and fullwidth
+/// characters and emoji
+const WIDE: [(u32, u32); 20] = [
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x1F300, 0x1F64F),
+    (0x1F680, 0x1FAFF),
+    (0x20000, 0x3FFFD),
+];
+
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges.iter().any(|&(start, end)| start <= c && c <= end)
+}
+
+/// Columns a character takes up in a terminal
+fn char_width(c: char) -> usize {
+    if c.is_control() || c == '\u{FEFF}' || in_ranges(c, &ZERO_WIDTH) {
+        0
+    } else if in_ranges(c, &WIDE) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Columns a text takes up in a terminal
+///
+/// CJK characters and emoji take two columns, combining accents and other
+/// zero width characters none, and emoji joined by a zero width joiner count
+/// as one. Curly quotes and other punctuation take one column, however many
+/// bytes they are.
+///
+/// ```
+/// use pquote::display_width;
+///
+/// assert_eq!(display_width("“Hi”"), 4);
+/// assert_eq!(display_width("日本語"), 6);
+/// ```
+#[must_use]
+pub fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut joined = false;
+    for c in text.chars() {
+        if !joined {
+            width += char_width(c);
+        }
+        joined = c == ZWJ;
+    }
+    width
+}
+
+/// Break text into lines of at most `width` columns
+///
+/// Lines are broken at spaces and between CJK characters, words wider than
+/// `width` are split wherever they have to be. Line breaks in the text are
+/// kept, leading and trailing whitespace of every line is dropped.
+#[must_use]
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    Wrap::new(width).lines(text)
+}
+
+/// Text padded on the left to end at column `width`
+#[must_use]
+pub fn align_right(text: &str, width: usize) -> String {
+    format!(
+        "{}{}",
+        " ".repeat(width.saturating_sub(display_width(text))),
+        text
+    )
+}
+
+/// How to wrap text, see [`Wrap::lines`] and [`Quote::wrapped`]
+///
+/// ```
+/// use pquote::Wrap;
+///
+/// let wrap = Wrap::new(20).indent(2).hanging(2).justify(true);
+/// assert_eq!(
+///     wrap.lines("Simplicity is prerequisite for reliability."),
+///     ["  Simplicity      is", "    prerequisite for", "    reliability."]
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Wrap {
+    width: usize,
+    indent: usize,
+    hanging: usize,
+    justify: bool,
+}
+
+impl Wrap {
+    /// Wrap at `width` columns, without indent or justification
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        Wrap {
+            width,
+            indent: 0,
+            hanging: 0,
+            justify: false,
+        }
+    }
+
+    /// Columns of space before every line, included in the width
+    #[must_use]
+    pub fn indent(self, indent: usize) -> Self {
+        Wrap { indent, ..self }
+    }
+
+    /// Columns of space before every line of a paragraph but its first, on
+    /// top of [`Wrap::indent`]
+    #[must_use]
+    pub fn hanging(self, hanging: usize) -> Self {
+        Wrap { hanging, ..self }
+    }
+
+    /// Whether to stretch the spaces of every line but the last of each
+    /// paragraph, so the lines end at the same column
+    #[must_use]
+    pub fn justify(self, justify: bool) -> Self {
+        Wrap { justify, ..self }
+    }
+
+    /// Width lines are wrapped at, indent included
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Wrapped lines of a text, every line of it a paragraph of its own
+    #[must_use]
+    pub fn lines(&self, text: &str) -> Vec<String> {
+        let first = self.width.saturating_sub(self.indent).max(1);
+        let rest = first.saturating_sub(self.hanging).max(1);
+        let mut out = Vec::new();
+        for paragraph in text.lines() {
+            let mut lines = fill(&tokens(paragraph), first, rest);
+            if lines.is_empty() {
+                // Blank lines between paragraphs stay
+                out.push(String::new());
+                continue;
+            }
+            let last = lines.len() - 1;
+            for (i, line) in lines.iter_mut().enumerate() {
+                let (indent, width) = match i {
+                    0 => (self.indent, first),
+                    _ => (self.indent + self.hanging, rest),
+                };
+                if self.justify && i < last {
+                    *line = justify(line, width);
+                }
+                line.insert_str(0, &" ".repeat(indent));
+            }
+            out.extend(lines);
+        }
+        // `lines` makes no paragraph after a trailing line break
+        while out.last().map_or(false, String::is_empty) {
+            out.pop();
+        }
+        out
+    }
+
+    /// Wrapped text, lines joined by line breaks
+    #[must_use]
+    pub fn fill(&self, text: &str) -> String {
+        self.lines(text).join("\n")
+    }
+}
+
+/// Wrap for a terminal, with the author underneath
+impl Quote {
+    /// The quote wrapped by `wrap`, a blank line and the attribution, e.g.
+    /// `— Alan Perlis, Epigrams on Programming (1982)`, aligned to the right
+    /// edge
+    ///
+    /// An attribution too wide for one line is wrapped on the left instead,
+    /// its lines after the first hanging under the name.
+    #[must_use]
+    pub fn wrapped(&self, wrap: &Wrap) -> String {
+        let attribution = attribution(self, "\u{2014}");
+        let mut out = wrap.fill(&self.quote);
+        out.push_str("\n\n");
+        if wrap.indent + display_width(&attribution) <= wrap.width {
+            out.push_str(&align_right(&attribution, wrap.width));
+        } else {
+            // Under the name, past the dash
+            let hanging = display_width("\u{2014} ");
+            out.push_str(&wrap.justify(false).hanging(hanging).fill(&attribution));
+        }
+        out
+    }
+}
+
+/// Author of a quote after a dash, followed by its work and year if it has one
+pub(crate) fn attribution(quote: &Quote, dash: &str) -> String {
+    let mut out = format!("{} {}", dash, quote.author.name);
+    if let Some(work) = quote.work {
+        out.push_str(", ");
+        out.push_str(work.title);
+        if let Some(year) = work.year {
+            let _ = write!(out, " ({})", year);
+        }
+    }
+    out
+}
+
+/// Measure quotes, e.g. to pick ones that fit a status bar or a chat message
+impl Quote {
+    /// Characters of the quote text, as most length limits count them
+    #[must_use]
+    pub fn char_count(&self) -> usize {
+        self.quote.chars().count()
+    }
+
+    /// Words of the quote text, anything between whitespace
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        self.quote.split_whitespace().count()
+    }
+
+    /// Columns the quote text takes up on a single line, see [`display_width`]
+    ///
+    /// Line breaks and runs of whitespace count as one space, as they would
+    /// in a status bar.
+    #[must_use]
+    pub fn display_width(&self) -> usize {
+        let words: Vec<&str> = self.quote.split_whitespace().collect();
+        display_width(&words.join(" "))
+    }
+
+    /// Lines the quote text takes up wrapped at `width` columns, see [`wrap`]
+    #[must_use]
+    pub fn line_count(&self, width: usize) -> usize {
+        Wrap::new(width).lines(&self.quote).len()
+    }
+}
+
+/// Piece of text lines are only broken before
+struct Token<'a> {
+    text: &'a str,
+    width: usize,
+    /// Whether it follows the token before without a space, as CJK
+    /// characters of a word do
+    glued: bool,
+}
+
+/// Words of a paragraph, with CJK characters split into tokens of their own
+fn tokens(paragraph: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    for word in paragraph.split_whitespace() {
+        let mut start = 0;
+        let mut width = 0;
+        let mut after_wide = false;
+        let mut joined = false;
+        for (i, c) in word.char_indices() {
+            // Characters joined to the one before are drawn with it
+            let c_width = if joined { 0 } else { char_width(c) };
+            joined = c == ZWJ;
+            if i > start && (c_width == 2 || (after_wide && c_width > 0)) {
+                tokens.push(Token {
+                    text: &word[start..i],
+                    width,
+                    glued: start > 0,
+                });
+                start = i;
+                width = 0;
+            }
+            width += c_width;
+            if c_width > 0 {
+                after_wide = c_width == 2;
+            }
+        }
+        tokens.push(Token {
+            text: &word[start..],
+            width,
+            glued: start > 0,
+        });
+    }
+    tokens
+}
+
+/// Greedily put tokens on lines of `first` columns, then `rest` columns
+fn fill(tokens: &[Token], first: usize, rest: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for token in tokens {
+        let space = usize::from(!line.is_empty() && !token.glued);
+        let width = if lines.is_empty() { first } else { rest };
+        if !line.is_empty() && line_width + space + token.width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        } else if space == 1 {
+            line.push(' ');
+            line_width += 1;
+        }
+        let width = if lines.is_empty() { first } else { rest };
+        if token.width <= width || line_width > 0 {
+            line.push_str(token.text);
+            line_width += token.width;
+            continue;
+        }
+        // Too wide for any line, split it wherever it fills one
+        for c in token.text.chars() {
+            let c_width = char_width(c);
+            let width = if lines.is_empty() { first } else { rest };
+            if line_width > 0 && line_width + c_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(c);
+            line_width += c_width;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Line with its spaces stretched to end at column `width`, the leftmost
+/// spaces stretched the most
+fn justify(line: &str, width: usize) -> String {
+    let gaps = line.matches(' ').count();
+    let extra = width.saturating_sub(display_width(line));
+    if gaps == 0 || extra == 0 {
+        return line.to_string();
+    }
+    let mut out = String::with_capacity(line.len() + extra);
+    let mut gap = 0;
+    for c in line.chars() {
+        out.push(c);
+        if c == ' ' {
+            let wider = extra / gaps + usize::from(gap < extra % gaps);
+            out.push_str(&" ".repeat(wider));
+            gap += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,70 @@
+use crate::{Quote, QUOTES, QUOTE_LIST};
+
+/// Marks an id that has no quote in the index
+const EMPTY: u16 = u16::MAX;
+
+/// Highest id in use, which sizes the index
+const MAX_ID: usize = max_id(&QUOTE_LIST) as usize;
+
+/// Position in [`QUOTES`] of every id, indexed by the id itself
+static INDEX: [u16; MAX_ID + 1] = build_index(&QUOTE_LIST);
+
+const fn max_id(quotes: &[Quote]) -> u32 {
+    let mut max = 0;
+    let mut i = 0;
+    while i < quotes.len() {
+        if quotes[i].id > max {
+            max = quotes[i].id;
+        }
+        i += 1;
+    }
+    max
+}
+
+const fn build_index(quotes: &[Quote]) -> [u16; MAX_ID + 1] {
+    assert!(quotes.len() < EMPTY as usize, "too many quotes to index");
+    let mut index = [EMPTY; MAX_ID + 1];
+    let mut i = 0;
+    while i < quotes.len() {
+        let id = quotes[i].id as usize;
+        assert!(index[id] == EMPTY, "duplicate quote id");
+        index[id] = i as u16;
+        i += 1;
+    }
+    index
+}
+
+/// Error returned by [`try_by_id`] when no quote has the requested id
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnknownIdError {
+    pub id: u32,
+}
+
+impl std::fmt::Display for UnknownIdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "no quote with id {}", self.id)
+    }
+}
+
+impl std::error::Error for UnknownIdError {}
+
+/// Get the quote with the given id in constant time
+///
+/// Ids are looked up through an index built at compile time, so this does
+/// not depend on the order of [`QUOTES`] or on ids being contiguous.
+#[must_use]
+pub fn by_id(id: u32) -> Option<&'static Quote> {
+    match INDEX.get(id as usize) {
+        Some(&pos) if pos != EMPTY => Some(&QUOTES[pos as usize]),
+        _ => None,
+    }
+}
+
+/// Get the quote with the given id, failing with [`UnknownIdError`] if there is none
+///
+/// # Errors
+///
+/// Returns [`UnknownIdError`] if no quote has the given id.
+pub fn try_by_id(id: u32) -> Result<&'static Quote, UnknownIdError> {
+    by_id(id).ok_or(UnknownIdError { id })
+}
@@ -0,0 +1,236 @@
+use crate::{Origin, OriginKind, Quote};
+use std::fmt::Write;
+
+/// What [`html_page`] and [`markdown_page`] put quotes under headings by
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GroupBy {
+    /// One heading per author, in alphabetical order
+    Author,
+    /// One heading per [`OriginKind`], in the order of [`OriginKind::ALL`]
+    Origin,
+}
+
+/// Render as Markdown or HTML, e.g. for a newsletter or a wiki page
+impl Quote {
+    /// Markdown blockquote followed by the attribution and a link to where
+    /// the quote was found
+    ///
+    /// ```
+    /// let q = pquote::by_id(44).unwrap();
+    /// assert!(q.to_markdown().starts_with("> Fools ignore complexity."));
+    /// ```
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let lines: Vec<&str> = self.quote.lines().map(str::trim).collect();
+        for (i, line) in lines.iter().enumerate() {
+            out.push_str("> ");
+            out.push_str(&markdown_escape(line));
+            // Backslash line break, so lines don't run together
+            out.push_str(if i + 1 < lines.len() { "\\\n" } else { "\n" });
+        }
+        out.push_str(">\n> \u{2014} ");
+        out.push_str(&markdown_escape(&self.author.name));
+        if let Some(work) = self.work {
+            let _ = write!(out, ", *{}*", markdown_escape(work.title));
+            if let Some(year) = work.year {
+                let _ = write!(out, " ({})", year);
+            }
+        }
+        match link(&self.permalink) {
+            Some(url) => {
+                let _ = write!(out, " ([source]({}))", link_destination(&url));
+            }
+            None => {
+                let _ = write!(out, " ({})", markdown_escape(&self.permalink.to_string()));
+            }
+        }
+        out.push('\n');
+        out
+    }
+
+    /// HTML `<blockquote>`, citing the permalink, with the attribution in a
+    /// `<footer>` and the title of the work in a `<cite>`
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<blockquote");
+        if let Some(url) = link(&self.permalink) {
+            let _ = write!(out, " cite=\"{}\"", html_escape(&url));
+        }
+        out.push_str(">\n  <p>");
+        let lines: Vec<String> = self
+            .quote
+            .lines()
+            .map(|line| html_escape(line.trim()))
+            .collect();
+        out.push_str(&lines.join("<br>\n  "));
+        let _ = write!(
+            out,
+            "</p>\n  <footer>&mdash; {}",
+            html_escape(&self.author.name)
+        );
+        if let Some(work) = self.work {
+            let _ = write!(out, ", <cite>{}</cite>", html_escape(work.title));
+            if let Some(year) = work.year {
+                let _ = write!(out, " ({})", year);
+            }
+        }
+        out.push_str("</footer>\n</blockquote>\n");
+        out
+    }
+}
+
+/// Standalone HTML page of quotes under a heading for each author or origin
+///
+/// The page has no external stylesheets or scripts, so it can be dropped
+/// into any static site as is.
+#[must_use]
+pub fn html_page(quotes: &[Quote], title: &str, by: GroupBy) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">
+<title>{title}</title>
+<style>
+body {{ max-width: 48rem; margin: 2rem auto; padding: 0 1rem; font-family: sans-serif; line-height: 1.5; }}
+blockquote {{ margin: 1.5rem 0; padding-left: 1rem; border-left: 4px solid #ccc; }}
+blockquote footer {{ color: #555; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+",
+        title = html_escape(title)
+    );
+    for (heading, quotes) in groups(quotes, by) {
+        let _ = write!(
+            out,
+            "<section>\n<h2 id=\"{}\">{}</h2>\n",
+            slug(&heading),
+            html_escape(&heading)
+        );
+        for quote in quotes {
+            out.push_str(&quote.to_html());
+        }
+        out.push_str("</section>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Markdown document of quotes under a heading for each author or origin
+#[must_use]
+pub fn markdown_page(quotes: &[Quote], title: &str, by: GroupBy) -> String {
+    let mut out = format!("# {}\n", markdown_escape(title));
+    for (heading, quotes) in groups(quotes, by) {
+        let _ = write!(out, "\n## {}\n", markdown_escape(&heading));
+        for quote in quotes {
+            out.push('\n');
+            out.push_str(&quote.to_markdown());
+        }
+    }
+    out
+}
+
+/// Quotes under their heading, headings in page order and quotes in the
+/// order they were given
+fn groups(quotes: &[Quote], by: GroupBy) -> Vec<(String, Vec<&Quote>)> {
+    let mut groups: Vec<(String, Vec<&Quote>)> = Vec::new();
+    match by {
+        GroupBy::Author => {
+            for quote in quotes {
+                match groups
+                    .iter_mut()
+                    .find(|(name, _)| *name == quote.author.name)
+                {
+                    Some((_, group)) => group.push(quote),
+                    None => groups.push((quote.author.name.to_string(), vec![quote])),
+                }
+            }
+            groups.sort_by_cached_key(|(name, _)| name.to_lowercase());
+        }
+        GroupBy::Origin => {
+            for kind in OriginKind::ALL.iter() {
+                let group: Vec<&Quote> = quotes
+                    .iter()
+                    .filter(|q| q.permalink.kind() == *kind)
+                    .collect();
+                if !group.is_empty() {
+                    groups.push((kind.to_string(), group));
+                }
+            }
+        }
+    }
+    groups
+}
+
+/// URL of an origin, `None` for quote files
+fn link(origin: &Origin) -> Option<String> {
+    let url = origin.to_string();
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Some(url)
+    } else {
+        None
+    }
+}
+
+/// URL percent-encoding the characters that would end a Markdown link early
+fn link_destination(url: &str) -> String {
+    let mut out = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            ' ' => out.push_str("%20"),
+            '(' => out.push_str("%28"),
+            ')' => out.push_str("%29"),
+            '<' => out.push_str("%3C"),
+            '>' => out.push_str("%3E"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Lowercase words joined by hyphens, for heading anchors
+fn slug(heading: &str) -> String {
+    heading
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Text with `&`, `<`, `>` and quotes turned into entities
+fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Text with the characters Markdown would format backslash-escaped
+fn markdown_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        let special = matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|')
+            // Would start a list at the beginning of a line
+            || (i == 0 && matches!(c, '-' | '+'));
+        if special {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
@@ -0,0 +1,121 @@
+use crate::{Origin, Quote, QUOTES};
+
+/// Site a quote was found on, i.e. an [`Origin`] without its permalink details
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OriginKind {
+    AZQuotes,
+    GoodReads,
+    JournalDev,
+    VimStartify,
+    StormConsultancy,
+    /// Quotes loaded at runtime from a quote file
+    User,
+}
+
+impl OriginKind {
+    /// Every kind of origin, in alphabetical order
+    pub const ALL: [OriginKind; 6] = [
+        OriginKind::AZQuotes,
+        OriginKind::GoodReads,
+        OriginKind::JournalDev,
+        OriginKind::StormConsultancy,
+        OriginKind::User,
+        OriginKind::VimStartify,
+    ];
+
+    /// Lowercase name of the origin, as accepted by [`str::parse`]
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            OriginKind::AZQuotes => "azquotes",
+            OriginKind::GoodReads => "goodreads",
+            OriginKind::JournalDev => "journaldev",
+            OriginKind::VimStartify => "vimstartify",
+            OriginKind::StormConsultancy => "stormconsultancy",
+            OriginKind::User => "user",
+        }
+    }
+
+    /// Other names the origin is parsed from, besides [`OriginKind::name`]
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            OriginKind::AZQuotes => &["azquote", "az"],
+            OriginKind::GoodReads => &["goodread"],
+            OriginKind::JournalDev => &[],
+            OriginKind::VimStartify => &["startify"],
+            OriginKind::StormConsultancy => &["storm"],
+            OriginKind::User => &["custom", "local"],
+        }
+    }
+
+    /// All quotes from this origin, see [`QuoteBook`](crate::QuoteBook) for
+    /// [`OriginKind::User`] ones
+    pub fn quotes(self) -> impl Iterator<Item = &'static Quote> {
+        QUOTES.iter().filter(move |q| q.permalink.kind() == self)
+    }
+}
+
+/// Kind of origin, e.g. [`OriginKind::AZQuotes`] for both AZ Quotes permalinks
+impl Origin {
+    #[must_use]
+    pub fn kind(&self) -> OriginKind {
+        match *self {
+            Origin::AZQuotesQuote(_) | Origin::AZQuotesAuthor(_) => OriginKind::AZQuotes,
+            Origin::GoodReads => OriginKind::GoodReads,
+            Origin::JournalDev => OriginKind::JournalDev,
+            Origin::VimStartify => OriginKind::VimStartify,
+            Origin::StormConsultancy(_) => OriginKind::StormConsultancy,
+            Origin::User(_) => OriginKind::User,
+        }
+    }
+}
+
+impl std::fmt::Display for OriginKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when parsing an [`OriginKind`] from an unknown name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOriginError {
+    name: String,
+}
+
+impl std::fmt::Display for ParseOriginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "unknown origin '{}', expected one of: ", self.name)?;
+        for (i, kind) in OriginKind::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(kind.name())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseOriginError {}
+
+impl std::str::FromStr for OriginKind {
+    type Err = ParseOriginError;
+
+    /// Parse an origin from its name or an alias, ignoring case and whitespace,
+    /// so `"Good Reads"` and `"goodreads"` are the same
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect();
+        OriginKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.name() == name || kind.aliases().contains(&name.as_str()))
+            .ok_or_else(|| ParseOriginError {
+                name: s.trim().to_string(),
+            })
+    }
+}
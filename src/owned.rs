@@ -0,0 +1,162 @@
+//! Owned counterparts of the `&'static` types, for deserializing with serde
+
+use crate::{Author, Origin, OriginKind, Quote, Rating, Tag, Work, WorkKind};
+use serde::de::Error as _;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Owned [`Quote`], as deserialized from a serialized one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteBuf {
+    /// Name of the author
+    pub author: String,
+    pub work: Option<WorkBuf>,
+    pub id: u32,
+    pub quote: String,
+    pub permalink: OriginBuf,
+    pub tags: Vec<Tag>,
+    pub rating: Rating,
+}
+
+/// Owned [`Work`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkBuf {
+    pub title: String,
+    pub year: Option<u16>,
+    pub kind: WorkKind,
+}
+
+/// Owned [`Origin`], serialized as its kind and URL
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginBuf {
+    pub kind: OriginKind,
+    pub url: String,
+}
+
+impl From<&Quote> for QuoteBuf {
+    fn from(quote: &Quote) -> Self {
+        QuoteBuf {
+            author: quote.author.name.to_string(),
+            work: quote.work.as_ref().map(WorkBuf::from),
+            id: quote.id,
+            quote: quote.quote.to_string(),
+            permalink: OriginBuf::from(&quote.permalink),
+            tags: quote.tags.to_vec(),
+            rating: quote.rating,
+        }
+    }
+}
+
+impl From<&Work> for WorkBuf {
+    fn from(work: &Work) -> Self {
+        WorkBuf {
+            title: work.title.to_string(),
+            year: work.year,
+            kind: work.kind,
+        }
+    }
+}
+
+impl From<&Origin> for OriginBuf {
+    fn from(origin: &Origin) -> Self {
+        OriginBuf {
+            kind: origin.kind(),
+            url: origin.to_string(),
+        }
+    }
+}
+
+/// Same shape as [`OriginBuf`]: `{ "kind": ..., "url": ... }`
+impl Serialize for Origin {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut origin = serializer.serialize_struct("Origin", 2)?;
+        origin.serialize_field("kind", &self.kind())?;
+        origin.serialize_field("url", &self.to_string())?;
+        origin.end()
+    }
+}
+
+/// Same shape as [`WorkBuf`]: `{ "title": ..., "year": ..., "kind": ... }`
+impl Serialize for Work {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut work = serializer.serialize_struct("Work", 3)?;
+        work.serialize_field("title", self.title)?;
+        work.serialize_field("year", &self.year)?;
+        work.serialize_field("kind", &self.kind)?;
+        work.end()
+    }
+}
+
+// Tags, ratings and kinds of work are written as their names. Their modules
+// are also compiled into `build.rs`, which has no serde to derive with.
+
+impl Serialize for Tag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Rating {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rating {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        by_name(&Rating::ALL, &name, |r| r.to_string())
+    }
+}
+
+/// Written in snake case, e.g. `mailing_list`
+impl Serialize for WorkKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&snake_case(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        by_name(&WorkKind::ALL, &name, snake_case)
+    }
+}
+
+fn snake_case(kind: WorkKind) -> String {
+    kind.to_string().replace(' ', "_")
+}
+
+/// Variant of `all` with a name, an error listing the names otherwise
+fn by_name<T: Copy, E: serde::de::Error>(
+    all: &[T],
+    name: &str,
+    name_of: impl Fn(T) -> String,
+) -> Result<T, E> {
+    all.iter()
+        .copied()
+        .find(|&v| name_of(v) == name)
+        .ok_or_else(|| {
+            let names: Vec<String> = all.iter().map(|&v| name_of(v)).collect();
+            E::custom(format!(
+                "unknown variant `{}`, expected one of: {}",
+                name,
+                names.join(", ")
+            ))
+        })
+}
+
+/// Serialize the author of a [`Quote`] as just their name, like [`QuoteBuf::author`]
+pub(crate) fn author_name<S: Serializer>(
+    author: &Author,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&author.name)
+}
@@ -0,0 +1,220 @@
+use crate::{
+    choose, search_authors, Author, AuthorMatch, OriginKind, Quote, QuoteBook, Rating, Tag, QUOTES,
+};
+
+/// Filters combined into one, a quote has to pass every filter given
+///
+/// Build it up and iterate over the quotes it matches, or pick one:
+///
+/// ```
+/// use pquote::{OriginKind, Query, Tag};
+///
+/// let query = Query::new()
+///     .author("Dijkstra")
+///     .origin(OriginKind::GoodReads)
+///     .max_len(140);
+/// assert!(query.iter().all(|q| q.author.name == "Edsger W. Dijkstra"));
+/// assert_eq!(query.count(), query.iter().count());
+///
+/// let short = Query::new().tag(Tag::Testing).safe().exclude_ids(&[1, 2, 3]);
+/// if let Some(q) = short.random() {
+///     println!("{}", q.quote);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Query {
+    author: Option<String>,
+    origin: Option<OriginKind>,
+    tags: Vec<Tag>,
+    rating: Option<Rating>,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    max_lines: Option<(usize, usize)>,
+    exclude: Vec<u32>,
+}
+
+impl Query {
+    /// Query matching every quote
+    #[must_use]
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Only quotes by the authors a name finds with [`search_authors`]
+    ///
+    /// A name that is exactly the name or an alias of some authors only finds
+    /// those, e.g. "RMS" leaves out Joe Armstrong.
+    #[must_use]
+    pub fn author(self, name: impl Into<String>) -> Self {
+        Query {
+            author: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Only quotes from this origin
+    #[must_use]
+    pub fn origin(self, origin: OriginKind) -> Self {
+        Query {
+            origin: Some(origin),
+            ..self
+        }
+    }
+
+    /// Only quotes with this tag, on top of any tags given before
+    #[must_use]
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Only quotes rated this or milder
+    #[must_use]
+    pub fn max_rating(self, rating: Rating) -> Self {
+        Query {
+            rating: Some(rating),
+            ..self
+        }
+    }
+
+    /// Only quotes that are safe to show anywhere, see [`Quote::is_safe`]
+    #[must_use]
+    pub fn safe(self) -> Self {
+        self.max_rating(Rating::Safe)
+    }
+
+    /// Only quotes of at least `min_len` characters, see [`Quote::char_count`]
+    #[must_use]
+    pub fn min_len(self, min_len: usize) -> Self {
+        Query {
+            min_len: Some(min_len),
+            ..self
+        }
+    }
+
+    /// Only quotes of at most `max_len` characters, see [`Quote::char_count`]
+    #[must_use]
+    pub fn max_len(self, max_len: usize) -> Self {
+        Query {
+            max_len: Some(max_len),
+            ..self
+        }
+    }
+
+    /// Only quotes that wrap to at most `lines` lines at `width` columns,
+    /// see [`Quote::line_count`]
+    #[must_use]
+    pub fn max_lines(self, lines: usize, width: usize) -> Self {
+        Query {
+            max_lines: Some((lines, width)),
+            ..self
+        }
+    }
+
+    /// Leave out quotes with these ids, on top of any left out before
+    #[must_use]
+    pub fn exclude_ids(mut self, ids: &[u32]) -> Self {
+        self.exclude.extend_from_slice(ids);
+        self
+    }
+
+    /// Built-in quotes matching the query, in the order of [`QUOTES`]
+    ///
+    /// Authors are looked up once, quotes are only checked as the iterator
+    /// gets to them.
+    pub fn iter(&self) -> impl Iterator<Item = &'static Quote> + '_ {
+        let authors = self.authors(|name| names(search_authors(name)));
+        QUOTES
+            .iter()
+            .filter(move |q| self.matches(q, authors.as_deref()))
+    }
+
+    /// Random quote matching the query, `None` if none does
+    #[must_use]
+    pub fn random(&self) -> Option<&'static Quote> {
+        let quotes: Vec<&'static Quote> = self.iter().collect();
+        choose(&quotes).copied()
+    }
+
+    /// Number of quotes matching the query
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// First quote matching the query
+    #[must_use]
+    pub fn first(&self) -> Option<&'static Quote> {
+        self.iter().next()
+    }
+
+    /// Names of the authors an author filter refers to, `None` without one
+    fn authors<'a>(&self, find: impl Fn(&str) -> Vec<&'a str>) -> Option<Vec<&'a str>> {
+        self.author.as_deref().map(find)
+    }
+
+    /// Whether a quote passes every filter, `authors` being the names the
+    /// author filter found
+    fn matches(&self, quote: &Quote, authors: Option<&[&str]>) -> bool {
+        authors.map_or(true, |names| {
+            std::iter::once(&quote.author)
+                .chain(quote.author.members.iter().copied())
+                .any(|a| names.contains(&&*a.name))
+        }) && self.origin.map_or(true, |o| quote.permalink.kind() == o)
+            && self.tags.iter().all(|&t| quote.has_tag(t))
+            && self.rating.map_or(true, |r| quote.rating <= r)
+            && self.min_len.map_or(true, |n| quote.char_count() >= n)
+            && self.max_len.map_or(true, |n| quote.char_count() <= n)
+            && self
+                .max_lines
+                .map_or(true, |(lines, width)| quote.line_count(width) <= lines)
+            && !self.exclude.contains(&quote.id)
+    }
+}
+
+/// Quotes with a tag, see [`Query::tag`] to combine it with other filters
+impl Tag {
+    /// All quotes with this tag
+    pub fn quotes(self) -> impl Iterator<Item = &'static Quote> {
+        QUOTES.iter().filter(move |q| q.has_tag(self))
+    }
+}
+
+/// Quotes by rating, see [`Query::max_rating`] to combine it with other filters
+impl Rating {
+    /// All quotes with this rating
+    pub fn quotes(self) -> impl Iterator<Item = &'static Quote> {
+        QUOTES.iter().filter(move |q| q.rating == self)
+    }
+
+    /// All quotes rated this or milder, e.g. `Rating::Controversial.at_most()`
+    /// leaves out crude quotes only
+    pub fn at_most(self) -> impl Iterator<Item = &'static Quote> {
+        QUOTES.iter().filter(move |q| q.rating <= self)
+    }
+}
+
+/// Names of the authors an author search found, only the exact matches if
+/// there are any
+fn names(found: Vec<(&Author, AuthorMatch)>) -> Vec<&str> {
+    let exact = found.iter().any(|&(_, m)| m == AuthorMatch::Exact);
+    found
+        .into_iter()
+        .filter(|&(_, m)| !exact || m == AuthorMatch::Exact)
+        .map(|(author, _)| &*author.name)
+        .collect()
+}
+
+/// Filter the quotes of a book, user quotes included
+impl QuoteBook {
+    /// Quotes of the book matching a query, in book order
+    ///
+    /// Authors are looked up among the authors of the book, like
+    /// [`QuoteBook::search_authors`].
+    pub fn query<'a>(&'a self, query: &'a Query) -> impl Iterator<Item = &'a Quote> {
+        let authors = query.authors(|name| names(self.search_authors(name)));
+        self.quotes()
+            .iter()
+            .filter(move |q| query.matches(q, authors.as_deref()))
+    }
+}
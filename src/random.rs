@@ -0,0 +1,143 @@
+use crate::{Quote, QUOTES};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Increment of the splitmix64 sequence (the golden ratio in 64 bits)
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Bumped on every call so quotes drawn in quick succession still differ
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Finalizer of the splitmix64 generator, turns any `u64` into a well mixed one
+pub(crate) fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Fresh random `u64` seeded from the clock, the stack address (ASLR) and a call counter
+pub(crate) fn entropy() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let local = 0u8;
+    let addr = &local as *const u8 as u64;
+    let count = COUNTER.fetch_add(GOLDEN_GAMMA, Ordering::Relaxed);
+    mix(nanos ^ addr.rotate_left(32) ^ count.wrapping_add(GOLDEN_GAMMA))
+}
+
+/// Map a random `u64` onto `0..len` without the bias of `%`
+pub(crate) fn bounded(x: u64, len: usize) -> usize {
+    ((u128::from(x) * len as u128) >> 64) as usize
+}
+
+/// Get a random quote
+///
+/// Uses a tiny built-in generator, so no extra dependencies are needed.
+/// It is not suitable for anything that needs real randomness.
+#[must_use]
+pub fn random() -> &'static Quote {
+    random_with(&mut entropy)
+}
+
+/// Get a random quote using your own source of random `u64`s
+///
+/// ```
+/// // xorshift64, but anything that yields `u64`s will do (e.g. `rand::Rng::gen`)
+/// let mut state = 0x2545_F491_4F6C_DD1D_u64;
+/// let quote = pquote::random_with(&mut || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// });
+/// println!("{}", quote.quote);
+/// ```
+pub fn random_with(next: &mut impl FnMut() -> u64) -> &'static Quote {
+    &QUOTES[bounded(next(), QUOTES.len())]
+}
+
+/// Pick a random element of a slice, e.g. a filtered list of quotes
+///
+/// Returns `None` if the slice is empty.
+#[must_use]
+pub fn choose<T>(items: &[T]) -> Option<&T> {
+    if items.is_empty() {
+        None
+    } else {
+        Some(&items[bounded(entropy(), items.len())])
+    }
+}
+
+/// Get the quote for a seed
+///
+/// The same seed always maps to the same quote, on every platform, for as
+/// long as the set of quotes stays the same.
+#[must_use]
+pub fn seeded(seed: u64) -> &'static Quote {
+    &QUOTES[seeded_index(seed, QUOTES.len())]
+}
+
+/// Pick the element of a slice for a seed, e.g. from a filtered list of quotes
+///
+/// Like [`seeded`], the same seed and slice always give the same element.
+/// Returns `None` if the slice is empty.
+#[must_use]
+pub fn choose_seeded<T>(items: &[T], seed: u64) -> Option<&T> {
+    if items.is_empty() {
+        None
+    } else {
+        Some(&items[seeded_index(seed, items.len())])
+    }
+}
+
+fn seeded_index(seed: u64, len: usize) -> usize {
+    bounded(mix(seed.wrapping_add(GOLDEN_GAMMA)), len)
+}
+
+/// Get the quote of the day for a date of the proleptic Gregorian calendar
+///
+/// Everyone asking for the same date gets the same quote. Returns `None` if
+/// the date does not exist, e.g. February 30th.
+#[must_use]
+pub fn quote_of_the_day(year: i32, month: u32, day: u32) -> Option<&'static Quote> {
+    days_from_civil(year, month, day).map(|days| seeded(days as u64))
+}
+
+/// Get the quote of the day for today's date in UTC
+#[must_use]
+pub fn daily() -> &'static Quote {
+    seeded(days_since_epoch())
+}
+
+/// Days since 1970-01-01 in UTC, the seed [`daily`] uses
+#[must_use]
+pub fn days_since_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or(0)
+}
+
+/// Days since 1970-01-01, see <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i32, month: u32, day: u32) -> Option<i64> {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if day == 0 || day > days_in_month {
+        return None;
+    }
+
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
@@ -0,0 +1,28 @@
+//! Also compiled into `build.rs` to check the ratings of `data/quotes.toml`,
+//! so it must not use anything from the crate.
+
+/// How suitable a quote is for a shared screen, from mildest to strongest
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rating {
+    /// Fine to show anywhere
+    Safe,
+    /// No bad language, but touches on religion, politics or drugs
+    Controversial,
+    /// Profanity or sexual references, left out entirely by the `sfw` feature
+    Crude,
+}
+
+impl Rating {
+    /// Every rating, from mildest to strongest
+    pub const ALL: [Rating; 3] = [Rating::Safe, Rating::Controversial, Rating::Crude];
+}
+
+impl std::fmt::Display for Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Rating::Safe => f.write_str("safe"),
+            Rating::Controversial => f.write_str("controversial"),
+            Rating::Crude => f.write_str("crude"),
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use crate::random::{bounded, entropy};
+use crate::toml::{self, Value};
+use crate::Quote;
+use std::convert::TryFrom;
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// How long to wait for another process to let go of a state file, longer
+/// than [`STALE_LOCK`] so a lock left behind is cleared rather than waited out
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Age at which a lock is taken to be left behind by a process that died,
+/// locks are only held for the moment it takes to read and write the file
+const STALE_LOCK: Duration = Duration::from_secs(3);
+
+const _: () = assert!(LOCK_TIMEOUT.as_millis() > STALE_LOCK.as_millis());
+
+/// Shuffle bag of quotes, every quote comes up once before any repeats
+///
+/// Only the ids of the quotes shown are kept, so the quotes to pick from can
+/// change between calls, e.g. with other filters or new quotes of your own.
+/// A new round starts once every quote given has been shown, and never with
+/// the quote that ended the last one.
+///
+/// ```
+/// use pquote::{Rotation, Tag};
+///
+/// let quotes: Vec<_> = Tag::Testing.quotes().cloned().collect();
+/// let mut rotation = Rotation::new();
+/// let mut ids: Vec<u32> = (0..quotes.len())
+///     .map(|_| rotation.next(&quotes).unwrap().id)
+///     .collect();
+/// ids.sort_unstable();
+/// ids.dedup();
+/// assert_eq!(ids.len(), quotes.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Rotation {
+    shown: Vec<u32>,
+    last: Option<u32>,
+}
+
+/// Where `pq --no-repeat` keeps its [`Rotation`]
+///
+/// That is `$XDG_STATE_HOME/pquote/rotation.toml`, falling back to
+/// `~/.local/state/pquote/rotation.toml` (`%LOCALAPPDATA%\pquote\rotation.toml`
+/// on Windows). Returns `None` if none of those variables are set.
+#[must_use]
+pub fn rotation_file() -> Option<PathBuf> {
+    let env = |key| {
+        std::env::var_os(key)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let dir = if cfg!(windows) {
+        env("LOCALAPPDATA")
+    } else {
+        env("XDG_STATE_HOME").or_else(|| env("HOME").map(|home| home.join(".local").join("state")))
+    };
+    dir.map(|dir| dir.join("pquote").join("rotation.toml"))
+}
+
+impl Rotation {
+    /// Rotation that has shown nothing yet
+    #[must_use]
+    pub fn new() -> Self {
+        Rotation::default()
+    }
+
+    /// Ids of the quotes shown in this round, oldest first
+    #[must_use]
+    pub fn shown(&self) -> &[u32] {
+        &self.shown
+    }
+
+    /// Pick a quote that has not been shown this round, `None` if there are
+    /// no quotes
+    pub fn next<'a>(&mut self, quotes: &'a [Quote]) -> Option<&'a Quote> {
+        self.next_with(quotes, &mut entropy)
+    }
+
+    /// Like [`Rotation::next`], using your own source of random `u64`s
+    pub fn next_with<'a>(
+        &mut self,
+        quotes: &'a [Quote],
+        next: &mut impl FnMut() -> u64,
+    ) -> Option<&'a Quote> {
+        let mut left: Vec<&Quote> = quotes
+            .iter()
+            .filter(|q| !self.shown.contains(&q.id))
+            .collect();
+        if left.is_empty() {
+            // New round for these quotes, others keep their place
+            self.shown.retain(|id| quotes.iter().all(|q| q.id != *id));
+            left = quotes.iter().collect();
+            if left.len() > 1 {
+                left.retain(|q| Some(q.id) != self.last);
+            }
+        }
+        let quote = *left.get(bounded(next(), left.len()))?;
+        self.shown.push(quote.id);
+        self.last = Some(quote.id);
+        Some(quote)
+    }
+
+    /// Read a rotation from a state file, a new one if there is no file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, or
+    /// [`io::ErrorKind::InvalidData`] if it is not a rotation.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Rotation> {
+        match fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Rotation::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Write the rotation to a state file, creating its directory
+    ///
+    /// The file is written next to its final place and renamed over it, so
+    /// it is never seen half written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file or its directory can't be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = sibling(path, &format!("{}.tmp", std::process::id()));
+        let written = (|| {
+            let mut file = fs::File::create(&temp)?;
+            file.write_all(self.to_string().as_bytes())?;
+            file.sync_all()?;
+            fs::rename(&temp, path)
+        })();
+        if written.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        written
+    }
+
+    /// Pick the next quote of the rotation kept in a state file, and save it
+    ///
+    /// The file is locked meanwhile, so shells starting at the same time
+    /// each get a quote of their own. A file that is not a rotation, e.g.
+    /// one cut short by a full disk, is started over.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or written, or
+    /// [`io::ErrorKind::WouldBlock`] if another process holds on to it.
+    pub fn next_in_file(path: impl AsRef<Path>, quotes: &[Quote]) -> io::Result<Option<&Quote>> {
+        let path = path.as_ref();
+        let _lock = Lock::acquire(path)?;
+        let mut rotation = match Rotation::load(path) {
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => Rotation::new(),
+            rotation => rotation?,
+        };
+        let quote = rotation.next(quotes);
+        rotation.save(path)?;
+        Ok(quote)
+    }
+}
+
+/// Lock file next to a state file, removed when dropped
+struct Lock(PathBuf);
+
+impl Lock {
+    fn acquire(path: &Path) -> io::Result<Lock> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let lock = sibling(path, "lock");
+        let start = SystemTime::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&lock) {
+                Ok(_) => return Ok(Lock(lock)),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(e) => return Err(e),
+            }
+            let age = fs::metadata(&lock)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok());
+            if age.map_or(false, |age| age > STALE_LOCK) {
+                clear_stale(&lock);
+                continue;
+            }
+            if start.elapsed().unwrap_or_default() > LOCK_TIMEOUT {
+                return Err(io::Error::new(
+                    io::ErrorKind::WouldBlock,
+                    format!("{} is locked by another process", path.display()),
+                ));
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+}
+
+/// Remove a lock left behind, unless another process got to it first
+///
+/// The lock is renamed out of the way before it is removed, so of several
+/// processes finding the same stale lock only one takes it. If the lock was
+/// replaced by a fresh one in the meantime, the fresh one is put back.
+fn clear_stale(lock: &Path) {
+    static CLEARED: AtomicUsize = AtomicUsize::new(0);
+    let n = CLEARED.fetch_add(1, Ordering::Relaxed);
+    let stale = sibling(lock, &format!("{}.{}.stale", std::process::id(), n));
+    if fs::rename(lock, &stale).is_err() {
+        return;
+    }
+    let age = fs::metadata(&stale)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+    if age.map_or(true, |age| age <= STALE_LOCK) {
+        // Fails if yet another process took the lock, which is then its own
+        let _ = fs::hard_link(&stale, lock);
+    }
+    let _ = fs::remove_file(&stale);
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// `path` with `.suffix` added to its file name
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Write the state file, e.g. `last = 44` and `shown = [3, 17, 44]`
+impl std::fmt::Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("# Quotes pq --no-repeat has shown since it last started over\n")?;
+        if let Some(last) = self.last {
+            writeln!(f, "last = {}", last)?;
+        }
+        let mut shown = String::new();
+        for (i, id) in self.shown.iter().enumerate() {
+            if i > 0 {
+                shown.push_str(", ");
+            }
+            let _ = write!(shown, "{}", id);
+        }
+        writeln!(f, "shown = [{}]", shown)
+    }
+}
+
+/// Error returned when a state file is not a [`Rotation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRotationError {
+    line: Option<usize>,
+    message: String,
+}
+
+impl std::fmt::Display for ParseRotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ParseRotationError {}
+
+impl std::str::FromStr for Rotation {
+    type Err = ParseRotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |message: String| ParseRotationError {
+            line: None,
+            message,
+        };
+        let id = |value: &Value| match value {
+            Value::Integer(id) => {
+                u32::try_from(*id).map_err(|_| error(format!("{} is not the id of a quote", id)))
+            }
+            other => Err(error(format!(
+                "ids must be integers, not {}",
+                other.type_name()
+            ))),
+        };
+        let root = toml::parse(s).map_err(|e| ParseRotationError {
+            line: Some(e.line),
+            message: e.message,
+        })?;
+
+        let mut rotation = Rotation::new();
+        for (key, value) in &root {
+            match (key.as_str(), value) {
+                ("last", value) => rotation.last = Some(id(value)?),
+                ("shown", Value::Array(ids)) => {
+                    rotation.shown = ids.iter().map(id).collect::<Result<_, _>>()?
+                }
+                ("shown", other) => {
+                    return Err(error(format!(
+                        "'shown' must be an array, not {}",
+                        other.type_name()
+                    )))
+                }
+                (other, _) => {
+                    return Err(error(format!(
+                        "unknown key '{}', expected last or shown",
+                        other
+                    )))
+                }
+            }
+        }
+        Ok(rotation)
+    }
+}
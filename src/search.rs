@@ -0,0 +1,291 @@
+use crate::author::normalize;
+use crate::terms::terms;
+use crate::{Author, Quote, AUTHORS};
+
+/// How an author matched a search, from best to worst
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AuthorMatch {
+    /// The query is the author's name or an alias
+    Exact,
+    /// The query is the author's surname
+    Surname,
+    /// The query is a whole word of a name, e.g. a first name
+    Word,
+    /// The query appears somewhere in a name
+    Substring,
+    /// The query is this many typos away from a name or a word of it
+    Fuzzy(usize),
+}
+
+/// Rank authors against a query, best match first
+///
+/// Matching ignores case and punctuation. Typos are only tolerated when
+/// nothing matches without them, so "kay" finds Alan Kay but not Ray Ozzie.
+/// Authors that match equally well are ordered by number of quotes.
+#[must_use]
+pub fn search_authors(query: &str) -> Vec<(&'static Author, AuthorMatch)> {
+    rank_authors(AUTHORS.iter(), query, |author| author.quotes().count())
+}
+
+/// [`search_authors`] over any set of authors, `count` gives their number of quotes
+pub(crate) fn rank_authors<'a>(
+    authors: impl Iterator<Item = &'a Author>,
+    query: &str,
+    count: impl Fn(&Author) -> usize,
+) -> Vec<(&'a Author, AuthorMatch)> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut found: Vec<(&'a Author, AuthorMatch)> = authors
+        .filter_map(|author| best_match(author, &query).map(|m| (author, m)))
+        .collect();
+    if found
+        .iter()
+        .any(|(_, m)| !matches!(m, AuthorMatch::Fuzzy(_)))
+    {
+        found.retain(|(_, m)| !matches!(m, AuthorMatch::Fuzzy(_)));
+    }
+    found.sort_by_key(|&(author, m)| (m, std::cmp::Reverse(count(author)), &*author.name));
+    found
+}
+
+/// Get the quotes of every author matching a query, best matching author first
+///
+/// See [`search_authors`] for how authors are matched and ranked. Quotes
+/// written together by several matching authors come up once.
+pub fn search_author(query: &str) -> impl Iterator<Item = &'static Quote> {
+    let mut seen: Vec<u32> = Vec::new();
+    search_authors(query)
+        .into_iter()
+        .flat_map(|(author, _)| author.quotes())
+        .filter(move |q| {
+            let new = !seen.contains(&q.id);
+            seen.push(q.id);
+            new
+        })
+}
+
+/// Suggest the author a query most likely meant, for "did you mean" messages
+///
+/// Looks further than [`search_authors`] does, so it is worth calling when
+/// that finds nothing. Returns `None` if no name is even close.
+#[must_use]
+pub fn suggest_author(query: &str) -> Option<&'static Author> {
+    closest_author(AUTHORS.iter(), query, |author| author.quotes().count())
+}
+
+/// [`suggest_author`] over any set of authors, `count` gives their number of quotes
+pub(crate) fn closest_author<'a>(
+    authors: impl Iterator<Item = &'a Author>,
+    query: &str,
+    count: impl Fn(&Author) -> usize,
+) -> Option<&'a Author> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return None;
+    }
+    let limit = query.chars().count() / 2;
+    authors
+        .filter_map(|author| {
+            candidates(author)
+                .map(|name| edit_distance(&query, &name))
+                .min()
+                .map(|d| (d, author))
+        })
+        .filter(|&(d, _)| d <= limit)
+        .min_by_key(|&(d, author)| (d, std::cmp::Reverse(count(author))))
+        .map(|(_, author)| author)
+}
+
+fn best_match(author: &Author, query: &str) -> Option<AuthorMatch> {
+    let names: Vec<String> = author.names().map(normalize).collect();
+    if names.iter().any(|name| name == query) {
+        Some(AuthorMatch::Exact)
+    } else if names
+        .iter()
+        .any(|name| name.rsplit(' ').next() == Some(query))
+    {
+        Some(AuthorMatch::Surname)
+    } else if names
+        .iter()
+        .any(|name| name.split(' ').any(|word| word == query))
+    {
+        Some(AuthorMatch::Word)
+    } else if names.iter().any(|name| name.contains(query)) {
+        Some(AuthorMatch::Substring)
+    } else {
+        let allowed = typos_allowed(query);
+        candidates(author)
+            .map(|name| edit_distance(query, &name))
+            .filter(|&d| d <= allowed)
+            .min()
+            .map(AuthorMatch::Fuzzy)
+    }
+}
+
+/// Every name of an author plus each word of those names
+fn candidates(author: &Author) -> impl Iterator<Item = String> + '_ {
+    author.names().map(normalize).flat_map(|name| {
+        let words: Vec<String> = name.split(' ').map(str::to_string).collect();
+        std::iter::once(name).chain(words)
+    })
+}
+
+/// One typo for every four letters, up to three
+fn typos_allowed(query: &str) -> usize {
+    match query.chars().count() {
+        0..=2 => 0,
+        len => ((len + 2) / 4).min(3),
+    }
+}
+
+/// Levenshtein distance, counting characters rather than bytes
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != cb);
+            cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// BM25 term frequency saturation
+const K1: f64 = 1.2;
+/// BM25 document length normalization
+const B: f64 = 0.75;
+
+// The index over `QUOTES`, made by `build.rs`: `POSTINGS` and `LENGTHS`
+include!(concat!(env!("OUT_DIR"), "/index.rs"));
+
+/// Inverted index over the text of a set of quotes, ranking searches with BM25
+///
+/// Words are lowercased, stripped of apostrophes and plural endings, and
+/// stop words are left out, both when indexing and when searching.
+#[derive(Debug)]
+pub struct Index<'a> {
+    quotes: &'a [Quote],
+    /// Every term, with the position of each quote containing it and how often it does
+    postings: std::collections::HashMap<String, Vec<(usize, u32)>>,
+    /// Number of terms in each quote
+    lengths: Vec<u32>,
+}
+
+/// Quote found by a search, along with how well it matched
+#[derive(Debug, Copy, Clone)]
+pub struct Hit<'a> {
+    pub quote: &'a Quote,
+    pub score: f64,
+}
+
+impl<'a> Index<'a> {
+    /// Index the text of some quotes
+    #[must_use]
+    pub fn new(quotes: &'a [Quote]) -> Self {
+        let mut postings: std::collections::HashMap<String, Vec<(usize, u32)>> =
+            std::collections::HashMap::new();
+        let mut lengths = Vec::with_capacity(quotes.len());
+        for (pos, quote) in quotes.iter().enumerate() {
+            let mut length = 0;
+            for (_, term) in terms(&quote.quote) {
+                length += 1;
+                let list = postings.entry(term).or_default();
+                match list.last_mut() {
+                    Some((last, count)) if *last == pos => *count += 1,
+                    _ => list.push((pos, 1)),
+                }
+            }
+            lengths.push(length);
+        }
+        Index {
+            quotes,
+            postings,
+            lengths,
+        }
+    }
+
+    /// Find the quotes containing any of the words in a query, best match first
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<Hit<'a>> {
+        rank(self.quotes, &self.lengths, query, |term| {
+            self.postings.get(term).map(Vec::as_slice)
+        })
+    }
+}
+
+/// Search the text of every quote, best match first
+///
+/// Uses an index over [`QUOTES`](crate::QUOTES) made at compile time, so
+/// nothing is built at runtime. See [`Index`] for how words are matched.
+#[must_use]
+pub fn search(query: &str) -> Vec<Hit<'static>> {
+    rank(&crate::QUOTES, &LENGTHS, query, |term| {
+        POSTINGS
+            .binary_search_by(|&(known, _)| known.cmp(term))
+            .ok()
+            .map(|i| POSTINGS[i].1)
+    })
+}
+
+/// Score `quotes` against a query with BM25, given the number of terms in
+/// each quote and the postings of a term
+fn rank<'a, 'p>(
+    quotes: &'a [Quote],
+    lengths: &[u32],
+    query: &str,
+    postings: impl Fn(&str) -> Option<&'p [(usize, u32)]>,
+) -> Vec<Hit<'a>> {
+    let mut query_terms: Vec<String> = terms(query).map(|(_, term)| term).collect();
+    query_terms.sort();
+    query_terms.dedup();
+
+    let count = quotes.len() as f64;
+    let total: u32 = lengths.iter().sum();
+    let average_length = f64::from(total) / count.max(1.0);
+    let mut scores = vec![0.0; quotes.len()];
+    for term in &query_terms {
+        if let Some(list) = postings(term) {
+            let found = list.len() as f64;
+            let idf = (1.0 + (count - found + 0.5) / (found + 0.5)).ln();
+            for &(pos, freq) in list {
+                let freq = f64::from(freq);
+                let length = f64::from(lengths[pos]) / average_length;
+                scores[pos] += idf * freq * (K1 + 1.0) / (freq + K1 * (1.0 - B + B * length));
+            }
+        }
+    }
+
+    let mut hits: Vec<Hit<'a>> = scores
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, score)| score > 0.0)
+        .map(|(pos, score)| Hit {
+            quote: &quotes[pos],
+            score,
+        })
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.quote.id.cmp(&b.quote.id))
+    });
+    hits
+}
+
+/// Byte ranges of the words in `text` that a search for `query` would match,
+/// for highlighting results
+#[must_use]
+pub fn match_ranges(text: &str, query: &str) -> Vec<std::ops::Range<usize>> {
+    let query_terms: Vec<String> = terms(query).map(|(_, term)| term).collect();
+    terms(text)
+        .filter(|(_, term)| query_terms.contains(term))
+        .map(|(range, _)| range)
+        .collect()
+}
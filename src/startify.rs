@@ -0,0 +1,389 @@
+use crate::book::author_named;
+use crate::{authors, Author, Origin, Quote, Rating, QUOTES};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// Share of words two quotes need in common to count as the same quote
+const SIMILARITY: f64 = 0.8;
+
+/// Error returned when a `fortune.vim` has no quotes or a broken string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStartifyError {
+    line: usize,
+    message: &'static str,
+}
+
+impl std::fmt::Display for ParseStartifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseStartifyError {}
+
+/// Read the quotes of vim-startify's `autoload/startify/fortune.vim`
+///
+/// Quotes are the entries of its Vimscript lists of lists, like
+/// `["Line one", "line two", '', '- Author']`. Lines of an entry are joined
+/// with newlines and a last `- Author` line gives the author. Quotes are
+/// numbered by their position in the file, starting at 1, and entries
+/// without an author are by [`authors::UNKNOWN`].
+///
+/// # Errors
+///
+/// Returns [`ParseStartifyError`] if a string is not terminated or the file
+/// has no list of quotes at all.
+pub fn parse_startify(src: &str) -> Result<Vec<Quote>, ParseStartifyError> {
+    let mut parser = Parser {
+        chars: join_continuations(src),
+        pos: 0,
+    };
+    let mut entries = Vec::new();
+    while parser.pos < parser.chars.len() {
+        match parser.chars[parser.pos].0 {
+            '[' => {
+                let start = parser.pos;
+                match parser.value()? {
+                    Some(Value::List(items)) if is_quote_list(&items) => {
+                        entries.extend(items.into_iter().filter_map(|item| match item {
+                            Value::List(lines) => Some(lines),
+                            Value::String(_) => None,
+                        }))
+                    }
+                    // Not a list of quotes, e.g. an index, so look inside it
+                    _ => parser.pos = start + 1,
+                }
+            }
+            // Strings outside of lists only matter for skipping over them, a
+            // `"` that doesn't end is a trailing comment
+            '"' | '\'' => {
+                if parser.value().is_err() {
+                    while !matches!(parser.peek(), Some('\n') | None) {
+                        parser.pos += 1;
+                    }
+                }
+            }
+            _ => parser.pos += 1,
+        }
+    }
+    if entries.is_empty() {
+        return Err(ParseStartifyError {
+            line: 1,
+            message: "no list of quotes found",
+        });
+    }
+
+    let mut known: Vec<Author> = Vec::new();
+    Ok(entries
+        .into_iter()
+        .filter_map(|lines| {
+            let mut lines: Vec<String> = lines
+                .into_iter()
+                .filter_map(|line| match line {
+                    Value::String(line) if !line.trim().is_empty() => Some(line),
+                    _ => None,
+                })
+                .collect();
+            let author = match lines.split_last() {
+                Some((last, text)) if !text.is_empty() => attribution(last),
+                _ => None,
+            };
+            let author = match author {
+                Some(name) => {
+                    lines.pop();
+                    let author = author_named(&name, known.iter());
+                    known.push(author.clone());
+                    author
+                }
+                None => authors::UNKNOWN,
+            };
+            let quote = lines
+                .iter()
+                .map(|line| line.trim())
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some((author, quote)).filter(|(_, quote)| !quote.is_empty())
+        })
+        .enumerate()
+        .map(|(i, (author, quote))| Quote {
+            author,
+            work: None,
+            id: i as u32 + 1,
+            quote: Cow::Owned(quote),
+            permalink: Origin::VimStartify,
+            tags: Cow::Borrowed(&[]),
+            rating: Rating::Safe,
+        })
+        .collect())
+}
+
+/// How quotes read with [`parse_startify`] compare to [`QUOTES`]
+#[derive(Debug, Clone, Default)]
+pub struct StartifyDiff {
+    /// Startify quotes we don't have at all
+    pub missing: Vec<Quote>,
+    /// Startify quotes we have with other wording or another author, theirs first
+    pub changed: Vec<(Quote, &'static Quote)>,
+    /// Our quotes from startify that it doesn't have (anymore)
+    pub removed: Vec<&'static Quote>,
+    /// Number of startify quotes we have exactly
+    pub unchanged: usize,
+}
+
+/// Compare quotes read with [`parse_startify`] to [`QUOTES`]
+///
+/// Quotes are the same if most of their words are, ignoring case and
+/// punctuation, so fixed typos show up as changed rather than missing.
+#[must_use]
+pub fn diff_startify(theirs: &[Quote]) -> StartifyDiff {
+    let ours: Vec<(&'static Quote, HashSet<String>)> =
+        QUOTES.iter().map(|q| (q, words(&q.quote))).collect();
+    let mut diff = StartifyDiff::default();
+    let mut found = HashSet::new();
+    for quote in theirs {
+        let their_words = words(&quote.quote);
+        let best = ours
+            .iter()
+            .map(|(q, our_words)| (q, similarity(&their_words, our_words)))
+            .filter(|&(_, similarity)| similarity >= SIMILARITY)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match best {
+            None => diff.missing.push(quote.clone()),
+            Some((ours, _)) => {
+                found.insert(ours.id);
+                let same_author = ours.author.name == quote.author.name
+                    || quote.author.name == authors::UNKNOWN.name;
+                if same_author && same_text(&ours.quote, &quote.quote) {
+                    diff.unchanged += 1;
+                } else {
+                    diff.changed.push((quote.clone(), ours));
+                }
+            }
+        }
+    }
+    diff.removed = QUOTES
+        .iter()
+        .filter(|q| q.permalink.is_vimstartify() && !found.contains(&q.id))
+        .collect();
+    diff
+}
+
+/// Report listing what is missing, changed and removed
+impl std::fmt::Display for StartifyDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} unchanged, {} missing, {} changed, {} removed",
+            self.unchanged,
+            self.missing.len(),
+            self.changed.len(),
+            self.removed.len()
+        )?;
+        for quote in &self.missing {
+            write!(
+                f,
+                "\n+ #{} {}\n    {}\n",
+                quote.id, quote.author, quote.quote
+            )?;
+        }
+        for (theirs, ours) in &self.changed {
+            write!(
+                f,
+                "\n~ #{} is our {}\n  - {}\n    {}\n  + {}\n    {}\n",
+                theirs.id, ours.id, ours.author, ours.quote, theirs.author, theirs.quote
+            )?;
+        }
+        for quote in &self.removed {
+            write!(
+                f,
+                "\n- our {} {}\n    {}\n",
+                quote.id, quote.author, quote.quote
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Author named by a `- Author` line
+fn attribution(line: &str) -> Option<String> {
+    let name = ["- ", "\u{2014}", "\u{2013}"]
+        .iter()
+        .find_map(|dash| line.trim().strip_prefix(dash))?
+        .trim();
+    Some(name.to_string()).filter(|name| !name.is_empty())
+}
+
+/// Whether a list holds lists of strings, as the lists of quotes do
+fn is_quote_list(items: &[Value]) -> bool {
+    !items.is_empty()
+        && items.iter().all(|item| match item {
+            Value::List(lines) => lines.iter().all(|line| matches!(line, Value::String(_))),
+            Value::String(_) => false,
+        })
+}
+
+/// Words of a text, lowercased and without punctuation
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Jaccard similarity of two sets of words
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let shared = a.intersection(b).count();
+    let all = a.len() + b.len() - shared;
+    if all == 0 {
+        1.0
+    } else {
+        shared as f64 / all as f64
+    }
+}
+
+/// Equal apart from whitespace
+fn same_text(a: &str, b: &str) -> bool {
+    a.split_whitespace().eq(b.split_whitespace())
+}
+
+/// Source with `\` continuation lines joined onto the line before and
+/// comments dropped, each character paired with its line number
+fn join_continuations(src: &str) -> Vec<(char, usize)> {
+    let mut chars = Vec::new();
+    for (number, line) in src.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('"') {
+            // `"` starts a comment, `"\ ` a comment between continuation lines
+            continue;
+        }
+        let line = match trimmed.strip_prefix('\\') {
+            Some(rest) => rest,
+            None => {
+                chars.push(('\n', number));
+                line
+            }
+        };
+        chars.extend(line.chars().map(|c| (c, number + 1)));
+    }
+    chars
+}
+
+/// Vimscript value, as far as quote lists go
+enum Value {
+    String(String),
+    List(Vec<Value>),
+}
+
+struct Parser {
+    chars: Vec<(char, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|&(c, _)| c)
+    }
+
+    fn error(&self, message: &'static str) -> ParseStartifyError {
+        let line = self
+            .chars
+            .get(self.pos.min(self.chars.len().saturating_sub(1)))
+            .map_or(1, |&(_, line)| line);
+        ParseStartifyError { line, message }
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// A string or a list, `None` for anything else
+    fn value(&mut self) -> Result<Option<Value>, ParseStartifyError> {
+        match self.peek() {
+            Some('\'') => self.single_quoted().map(|s| Some(Value::String(s))),
+            Some('"') => self.double_quoted().map(|s| Some(Value::String(s))),
+            Some('[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                loop {
+                    self.skip_space();
+                    if self.peek() == Some(']') {
+                        self.pos += 1;
+                        return Ok(Some(Value::List(items)));
+                    }
+                    match self.value()? {
+                        Some(item) => items.push(item),
+                        None => return Ok(None),
+                    }
+                    self.skip_space();
+                    match self.peek() {
+                        Some(',') => self.pos += 1,
+                        Some(']') => {}
+                        _ => return Ok(None),
+                    }
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// `'...'` string, where `''` is a quote
+    fn single_quoted(&mut self) -> Result<String, ParseStartifyError> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut s = String::new();
+        loop {
+            match self.peek() {
+                Some('\'') if self.chars.get(self.pos + 1).map(|&(c, _)| c) == Some('\'') => {
+                    s.push('\'');
+                    self.pos += 2;
+                }
+                Some('\'') => {
+                    self.pos += 1;
+                    return Ok(s);
+                }
+                Some('\n') | None => {
+                    self.pos = start;
+                    return Err(self.error("unterminated string"));
+                }
+                Some(c) => {
+                    s.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    /// `"..."` string with backslash escapes
+    fn double_quoted(&mut self) -> Result<String, ParseStartifyError> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut s = String::new();
+        loop {
+            let c = match self.peek() {
+                Some('\n') | None => {
+                    self.pos = start;
+                    return Err(self.error("unterminated string"));
+                }
+                Some(c) => c,
+            };
+            self.pos += 1;
+            match c {
+                '"' => return Ok(s),
+                '\\' => {
+                    let escaped = self.peek().unwrap_or('\\');
+                    self.pos += 1;
+                    s.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'e' => '\u{1b}',
+                        c => c,
+                    });
+                }
+                c => s.push(c),
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Also compiled into `build.rs` to check the tags of `data/quotes.toml`, so
+//! it must not use anything from the crate.
+
+/// Topic of a quote, a quote can have several
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Complexity,
+    Computing,
+    Craft,
+    Debugging,
+    Design,
+    Humor,
+    Languages,
+    Learning,
+    Management,
+    Performance,
+    Philosophy,
+    Security,
+    Testing,
+}
+
+impl Tag {
+    /// Every tag, in alphabetical order
+    pub const ALL: [Tag; 13] = [
+        Tag::Complexity,
+        Tag::Computing,
+        Tag::Craft,
+        Tag::Debugging,
+        Tag::Design,
+        Tag::Humor,
+        Tag::Languages,
+        Tag::Learning,
+        Tag::Management,
+        Tag::Performance,
+        Tag::Philosophy,
+        Tag::Security,
+        Tag::Testing,
+    ];
+
+    /// Lowercase name of the tag, as accepted by [`str::parse`]
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Tag::Complexity => "complexity",
+            Tag::Computing => "computing",
+            Tag::Craft => "craft",
+            Tag::Debugging => "debugging",
+            Tag::Design => "design",
+            Tag::Humor => "humor",
+            Tag::Languages => "languages",
+            Tag::Learning => "learning",
+            Tag::Management => "management",
+            Tag::Performance => "performance",
+            Tag::Philosophy => "philosophy",
+            Tag::Security => "security",
+            Tag::Testing => "testing",
+        }
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error returned when parsing a [`Tag`] from an unknown name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTagError {
+    name: String,
+}
+
+impl std::fmt::Display for ParseTagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "unknown tag '{}', expected one of: ", self.name)?;
+        for (i, tag) in Tag::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(tag.name())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseTagError {}
+
+impl std::str::FromStr for Tag {
+    type Err = ParseTagError;
+
+    /// Parse a tag from its name, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        Tag::ALL
+            .iter()
+            .copied()
+            .find(|tag| tag.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseTagError {
+                name: name.to_string(),
+            })
+    }
+}
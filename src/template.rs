@@ -0,0 +1,350 @@
+use crate::layout::display_width;
+use crate::{Quote, Wrap};
+
+/// Format string for [`Quote::render`], e.g. `{quote} — {author} <{link}>`
+///
+/// * `{field}` is replaced by a field of the quote, one of `id`, `quote`,
+///   `author`, `work`, `year`, `origin`, `link`, `tags` and `rating`. Missing
+///   works and years are empty, tags are separated by `, `.
+/// * Modifiers follow the field after `:`, like `{quote:width=60:indent=4}`.
+///   `width=N` wraps the text at `N` columns, `indent=N` puts `N` spaces
+///   before every line and `pad=N` fills every line up to `N` columns.
+/// * `{#field}...{/field}` is only rendered if the field is not empty,
+///   `{^field}...{/field}` only if it is, e.g. `{#work}, {work}{/work}`.
+/// * `{{` and `}}` are literal braces, `\n`, `\t` and `\\` are a newline, a
+///   tab and a backslash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Field(Field, Modifiers),
+    Section {
+        field: Field,
+        inverted: bool,
+        parts: Vec<Part>,
+    },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Field {
+    Id,
+    Quote,
+    Author,
+    Work,
+    Year,
+    Origin,
+    Link,
+    Tags,
+    Rating,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+struct Modifiers {
+    width: Option<usize>,
+    indent: usize,
+    pad: usize,
+}
+
+impl Field {
+    const ALL: [Field; 9] = [
+        Field::Id,
+        Field::Quote,
+        Field::Author,
+        Field::Work,
+        Field::Year,
+        Field::Origin,
+        Field::Link,
+        Field::Tags,
+        Field::Rating,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Field::Id => "id",
+            Field::Quote => "quote",
+            Field::Author => "author",
+            Field::Work => "work",
+            Field::Year => "year",
+            Field::Origin => "origin",
+            Field::Link => "link",
+            Field::Tags => "tags",
+            Field::Rating => "rating",
+        }
+    }
+
+    fn value(self, quote: &Quote) -> String {
+        match self {
+            Field::Id => quote.id.to_string(),
+            Field::Quote => quote.quote.to_string(),
+            Field::Author => quote.author.name.to_string(),
+            Field::Work => quote.work.map(|w| w.title).unwrap_or_default().to_string(),
+            Field::Year => quote
+                .work
+                .and_then(|w| w.year)
+                .map(|y| y.to_string())
+                .unwrap_or_default(),
+            Field::Origin => quote.permalink.kind().name().to_string(),
+            Field::Link => quote.permalink.to_string(),
+            Field::Tags => quote
+                .tags
+                .iter()
+                .map(|t| t.name())
+                .collect::<Vec<_>>()
+                .join(", "),
+            Field::Rating => quote.rating.to_string(),
+        }
+    }
+}
+
+impl Modifiers {
+    fn apply(self, value: String) -> String {
+        if self == Modifiers::default() {
+            return value;
+        }
+        let indent = " ".repeat(self.indent);
+        let lines: Vec<String> = match self.width {
+            Some(width) => Wrap::new(width).indent(self.indent).lines(&value),
+            None => value
+                .lines()
+                .map(|line| format!("{}{}", indent, line))
+                .collect(),
+        };
+        lines
+            .iter()
+            .map(|line| {
+                let fill = self.pad.saturating_sub(display_width(line));
+                format!("{}{}", line, " ".repeat(fill))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Template {
+    /// Parse a template, see [`Template`] for the syntax
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseTemplateError`] for unknown fields or modifiers,
+    /// unclosed braces and sections that are not closed or closed twice.
+    pub fn parse(src: &str) -> Result<Template, ParseTemplateError> {
+        let mut parser = Parser {
+            chars: src.chars().collect(),
+            pos: 0,
+        };
+        let parts = parser.parts(None)?;
+        Ok(Template { parts })
+    }
+}
+
+impl std::str::FromStr for Template {
+    type Err = ParseTemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::parse(s)
+    }
+}
+
+/// Fill in a [`Template`]
+impl Quote {
+    /// ```
+    /// use pquote::Template;
+    ///
+    /// let template: Template = "#{id} {author}{#work}, {work}{/work}".parse().unwrap();
+    /// let q = pquote::by_id(44).unwrap();
+    /// assert_eq!(q.render(&template), "#44 Alan Perlis, Epigrams on Programming");
+    /// ```
+    #[must_use]
+    pub fn render(&self, template: &Template) -> String {
+        let mut out = String::new();
+        render_parts(self, &template.parts, &mut out);
+        out
+    }
+}
+
+fn render_parts(quote: &Quote, parts: &[Part], out: &mut String) {
+    for part in parts {
+        match part {
+            Part::Text(text) => out.push_str(text),
+            Part::Field(field, modifiers) => out.push_str(&modifiers.apply(field.value(quote))),
+            Part::Section {
+                field,
+                inverted,
+                parts,
+            } => {
+                if field.value(quote).is_empty() == *inverted {
+                    render_parts(quote, parts, out);
+                }
+            }
+        }
+    }
+}
+
+/// Error returned when a [`Template`] can't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTemplateError {
+    column: usize,
+    message: String,
+}
+
+impl std::fmt::Display for ParseTemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseTemplateError {}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, column: usize, message: String) -> ParseTemplateError {
+        ParseTemplateError {
+            column: column + 1,
+            message,
+        }
+    }
+
+    /// Parts up to the end, or up to `{/field}` inside a section
+    fn parts(&mut self, section: Option<(Field, usize)>) -> Result<Vec<Part>, ParseTemplateError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        while let Some(&c) = self.chars.get(self.pos) {
+            let next = self.chars.get(self.pos + 1).copied();
+            match (c, next) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    text.push(c);
+                    self.pos += 2;
+                }
+                ('\\', Some(escaped @ ('n' | 't' | '\\'))) => {
+                    text.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        _ => '\\',
+                    });
+                    self.pos += 2;
+                }
+                ('}', _) => {
+                    return Err(self.error(self.pos, "unmatched '}', use '}}' for a brace".into()))
+                }
+                ('{', _) => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    let start = self.pos;
+                    let tag = self.tag()?;
+                    if let Some(name) = tag.strip_prefix('/') {
+                        let field = self.field(name, start)?;
+                        return match section {
+                            Some((open, _)) if open == field => Ok(parts),
+                            _ => Err(self.error(
+                                start,
+                                format!("'{{/{}}}' without a section to close", name),
+                            )),
+                        };
+                    }
+                    let inverted = tag.starts_with('^');
+                    if let Some(name) = tag.strip_prefix('#').or_else(|| tag.strip_prefix('^')) {
+                        let field = self.field(name, start)?;
+                        let parts_inside = self.parts(Some((field, start)))?;
+                        parts.push(Part::Section {
+                            field,
+                            inverted,
+                            parts: parts_inside,
+                        });
+                    } else {
+                        let mut pieces = tag.split(':');
+                        let field = self.field(pieces.next().unwrap_or_default(), start)?;
+                        let mut modifiers = Modifiers::default();
+                        for modifier in pieces {
+                            self.modifier(&mut modifiers, modifier, start)?;
+                        }
+                        parts.push(Part::Field(field, modifiers));
+                    }
+                }
+                _ => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        if let Some((field, start)) = section {
+            return Err(self.error(start, format!("section '{}' is never closed", field.name())));
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(parts)
+    }
+
+    /// Text between `{` and `}`, with the position after the `}`
+    fn tag(&mut self) -> Result<String, ParseTemplateError> {
+        let start = self.pos;
+        let len = self.chars[start..]
+            .iter()
+            .position(|&c| c == '}')
+            .ok_or_else(|| self.error(start, "unclosed '{', use '{{' for a brace".into()))?;
+        self.pos = start + len + 1;
+        Ok(self.chars[start + 1..start + len].iter().collect())
+    }
+
+    fn field(&self, name: &str, start: usize) -> Result<Field, ParseTemplateError> {
+        let name = name.trim();
+        Field::ALL
+            .iter()
+            .copied()
+            .find(|field| field.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Field::ALL.iter().map(|f| f.name()).collect();
+                self.error(
+                    start,
+                    format!(
+                        "unknown field '{}', expected one of: {}",
+                        name,
+                        names.join(", ")
+                    ),
+                )
+            })
+    }
+
+    fn modifier(
+        &self,
+        modifiers: &mut Modifiers,
+        modifier: &str,
+        start: usize,
+    ) -> Result<(), ParseTemplateError> {
+        let (name, value) = modifier.split_once('=').unwrap_or((modifier, ""));
+        let value: usize = value.trim().parse().map_err(|_| {
+            self.error(
+                start,
+                format!(
+                    "modifier '{}' needs a number, like {}=4",
+                    modifier,
+                    name.trim()
+                ),
+            )
+        })?;
+        match name.trim() {
+            "width" => modifiers.width = Some(value),
+            "indent" => modifiers.indent = value,
+            "pad" => modifiers.pad = value,
+            _ => {
+                return Err(self.error(
+                    start,
+                    format!(
+                        "unknown modifier '{}', expected width, indent or pad",
+                        name.trim()
+                    ),
+                ))
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+//! How the text of quotes is split into search terms
+//!
+//! Also compiled into `build.rs` to index `data/quotes.toml`, so it must not
+//! use anything from the crate.
+
+/// Words too common to be worth searching for
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be",
+    "because", "been", "before", "being", "but", "by", "can", "could", "did", "do", "does",
+    "doing", "dont", "for", "from", "had", "has", "have", "having", "he", "her", "here", "him",
+    "his", "how", "i", "if", "im", "in", "into", "is", "isnt", "it", "its", "itself", "ive",
+    "just", "me", "more", "most", "my", "no", "nor", "not", "of", "off", "on", "once", "only",
+    "or", "other", "our", "out", "over", "own", "same", "she", "should", "so", "some", "such",
+    "than", "that", "the", "their", "them", "then", "there", "these", "they", "this", "those",
+    "to", "too", "very", "was", "we", "were", "what", "when", "which", "who", "will", "with",
+    "you", "your",
+];
+
+/// Searchable terms of a text, with where each one is in the text
+pub(crate) fn terms(text: &str) -> impl Iterator<Item = (std::ops::Range<usize>, String)> + '_ {
+    words(text).filter_map(move |range| {
+        let word: String = text[range.clone()]
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        if word.is_empty() || STOP_WORDS.contains(&word.as_str()) {
+            None
+        } else {
+            Some((range, stem(word)))
+        }
+    })
+}
+
+/// Byte ranges of the words in a text, apostrophes included
+fn words(text: &str) -> impl Iterator<Item = std::ops::Range<usize>> + '_ {
+    let is_word = |c: char| c.is_alphanumeric() || c == '\'' || c == '\u{2019}';
+    let mut chars = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        let (start, _) = chars.by_ref().find(|&(_, c)| is_word(c))?;
+        let mut end = text.len();
+        while let Some(&(i, c)) = chars.peek() {
+            if !is_word(c) {
+                end = i;
+                break;
+            }
+            chars.next();
+        }
+        Some(start..end)
+    })
+}
+
+/// Strip plural endings off a lowercase word
+fn stem(mut word: String) -> String {
+    if word.len() > 4 && word.ends_with("ies") {
+        word.truncate(word.len() - 3);
+        word.push('y');
+    } else if word.len() > 3
+        && word.ends_with('s')
+        && !word.ends_with("ss")
+        && !word.ends_with("us")
+        && !word.ends_with("is")
+    {
+        word.pop();
+    }
+    word
+}
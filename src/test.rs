@@ -1,4 +1,12 @@
-use crate::{Origin, QUOTES};
+use crate::{
+    align_right, by_author, by_id, choose, choose_seeded, diff_startify, display_width,
+    fortune_index, html_page, markdown_page, match_ranges, parse_startify, quote_of_the_day,
+    random, random_with, search, search_author, search_authors, seeded, suggest_author, to_fortune,
+    try_by_id, wrap, Author, AuthorMatch, Color, ColorChoice, ColorDepth, Format, Frame, GroupBy,
+    Hit, Index, Origin, OriginKind, Query, Quote, QuoteBook, Rating, Rotation, Style, Tag,
+    Template, Theme, Themes, UnknownIdError, WorkKind, Wrap, AUTHORS, QUOTES,
+};
+use std::borrow::Cow;
 
 #[test]
 fn all_quotes() {
@@ -16,7 +24,7 @@ fn grammar() -> std::result::Result<(), &'static str> {
     let mut b_grammar = Vec::new();
     for q in QUOTES.iter() {
         if q.quote.chars().next().unwrap().is_ascii_lowercase()
-            || q.author.chars().next().unwrap().is_ascii_lowercase()
+            || q.author.name.chars().next().unwrap().is_ascii_lowercase()
         {
             b_grammar.push(q)
         }
@@ -37,7 +45,7 @@ fn duplicates_quote() -> std::result::Result<(), &'static str> {
     let mut vec = Vec::with_capacity(QUOTES.len());
     let mut dup: Vec<(usize, &str)> = Vec::new();
     for q in QUOTES.iter() {
-        vec.push(q.quote)
+        vec.push(&*q.quote)
     }
     vec.sort();
     for i in 0..vec.len() {
@@ -58,6 +66,7 @@ fn duplicates_quote() -> std::result::Result<(), &'static str> {
 }
 
 #[test]
+#[cfg(not(feature = "sfw"))]
 #[allow(clippy::needless_range_loop)]
 fn wrong_id() -> std::result::Result<(), &'static str> {
     let mut wrong: Vec<(u32, usize)> = Vec::new();
@@ -82,14 +91,10 @@ fn wrong_id() -> std::result::Result<(), &'static str> {
 fn authors() {
     let mut map: std::collections::HashMap<&'static str, u32> = std::collections::HashMap::new();
     for quote in QUOTES.iter() {
-        *map.entry(quote.author).or_insert(0) += 1;
+        *map.entry(&quote.author.name).or_insert(0) += 1;
     }
     // Get length of largest key
-    let j = map
-        .keys()
-        .max_by(|x, y| x.len().cmp(&y.len()))
-        .unwrap()
-        .len();
+    let j = map.keys().map(|name| display_width(name)).max().unwrap();
     let map_clone = map.clone();
     let last = map_clone.keys().last().unwrap();
     println!("┌{}───────┬──────────┐", "─".repeat(j));
@@ -97,33 +102,22 @@ fn authors() {
     println!("├{}───────┼──────────┤", "─".repeat(j));
     for (name, count) in map {
         match count {
-            1..=9 => {
-                if name.contains("“") {
-                    println!(
-                        "│ {}{} │    {}     │",
-                        name,
-                        " ".repeat(j - name.len() + 9),
-                        count
-                    )
-                } else {
-                    println!(
-                        "│ {}{} │    {}     │",
-                        name,
-                        " ".repeat(j - name.len() + 5),
-                        count
-                    )
-                }
-            }
+            1..=9 => println!(
+                "│ {}{} │    {}     │",
+                name,
+                " ".repeat(j - display_width(name) + 5),
+                count
+            ),
             10..=99 => println!(
                 "│ {}{} │    {}    │",
                 name,
-                " ".repeat(j - name.len() + 5),
+                " ".repeat(j - display_width(name) + 5),
                 count
             ),
             _ => println!(
                 "│ {}{} │    {}  │",
                 name,
-                " ".repeat(j - name.len() + 5),
+                " ".repeat(j - display_width(name) + 5),
                 count
             ),
         }
@@ -137,7 +131,7 @@ fn authors() {
 
 #[test]
 fn origins() -> std::result::Result<(), &'static str> {
-    let mut v: Vec<(u32, Origin)> = Vec::new();
+    let mut v: Vec<(u32, &Origin)> = Vec::new();
     for q in QUOTES.iter() {
         if q.permalink.is_azquotes()
             || q.permalink.is_azquotesauthor()
@@ -149,7 +143,7 @@ fn origins() -> std::result::Result<(), &'static str> {
         {
             println!("Quote {}: has Origin: {}", q.id, q.permalink)
         } else {
-            v.push((q.id, q.permalink))
+            v.push((q.id, &q.permalink))
         }
     }
 
@@ -163,3 +157,1271 @@ fn origins() -> std::result::Result<(), &'static str> {
         Ok(())
     }
 }
+
+#[test]
+fn origin_kinds() {
+    for input in ["azquotes", "AZQuotes", "AZ Quote", " azquote "] {
+        assert_eq!(input.parse(), Ok(OriginKind::AZQuotes));
+    }
+    assert_eq!("Good Reads".parse(), Ok(OriginKind::GoodReads));
+    assert_eq!("journal dev".parse(), Ok(OriginKind::JournalDev));
+    assert_eq!("Vim Startify".parse(), Ok(OriginKind::VimStartify));
+    assert_eq!(
+        "Storm\tConsultancy".parse(),
+        Ok(OriginKind::StormConsultancy)
+    );
+    let err = "wikiquote".parse::<OriginKind>().unwrap_err().to_string();
+    assert!(err.starts_with("unknown origin 'wikiquote'"));
+    assert!(OriginKind::ALL.iter().all(|o| err.contains(o.name())));
+
+    for kind in OriginKind::ALL.iter() {
+        assert_eq!(kind.name().parse(), Ok(*kind));
+        if *kind != OriginKind::User {
+            assert!(kind.quotes().count() > 0, "no quotes from {}", kind);
+        }
+    }
+    assert_eq!(
+        OriginKind::ALL
+            .iter()
+            .map(|o| o.quotes().count())
+            .sum::<usize>(),
+        QUOTES.len()
+    );
+    assert!(OriginKind::AZQuotes
+        .quotes()
+        .all(|q| q.permalink.is_azquotes()));
+}
+
+#[test]
+fn lookup_by_id() {
+    for q in QUOTES.iter() {
+        assert_eq!(by_id(q.id).map(|found| &found.quote), Some(&q.quote));
+    }
+    assert!(by_id(0).is_none());
+    assert!(by_id(u32::MAX).is_none());
+    let missing = QUOTES.iter().map(|q| q.id).max().unwrap() + 1;
+    assert_eq!(
+        try_by_id(missing).unwrap_err(),
+        UnknownIdError { id: missing }
+    );
+}
+
+#[test]
+fn random_selection() {
+    assert!(by_id(random().id).is_some());
+    assert_eq!(random_with(&mut || 0).id, QUOTES[0].id);
+    assert_eq!(
+        random_with(&mut || u64::MAX).id,
+        QUOTES[QUOTES.len() - 1].id
+    );
+    assert!(choose::<u32>(&[]).is_none());
+    assert_eq!(choose(&[7]), Some(&7));
+}
+
+#[test]
+fn seeded_selection() {
+    // These must only change when quotes are added or removed
+    if !cfg!(feature = "sfw") {
+        assert_eq!(seeded(0).id, 38);
+        assert_eq!(seeded(42).id, 84);
+    }
+    assert_eq!(
+        quote_of_the_day(1970, 1, 1).map(|q| q.id),
+        Some(seeded(0).id)
+    );
+    // 2000-03-01 is day 11017 of the unix epoch
+    assert_eq!(
+        quote_of_the_day(2000, 3, 1).map(|q| q.id),
+        Some(seeded(11_017).id)
+    );
+    assert!(quote_of_the_day(2020, 2, 29).is_some());
+    assert!(quote_of_the_day(2021, 2, 29).is_none());
+    assert!(quote_of_the_day(1900, 2, 29).is_none());
+    assert!(quote_of_the_day(2021, 13, 1).is_none());
+    assert!(quote_of_the_day(2021, 4, 31).is_none());
+    assert_eq!(
+        choose_seeded(&QUOTES, 42).map(|q| q.id),
+        Some(seeded(42).id)
+    );
+    assert!(choose_seeded::<u32>(&[], 42).is_none());
+}
+
+#[test]
+fn tags() -> std::result::Result<(), &'static str> {
+    let untagged: Vec<u32> = QUOTES
+        .iter()
+        .filter(|q| q.tags.is_empty())
+        .map(|q| q.id)
+        .collect();
+    if !untagged.is_empty() {
+        println!("The following quotes have no tags: {:?}", untagged);
+        return Err("Untagged quotes found");
+    }
+    for tag in Tag::ALL.iter() {
+        assert_eq!(tag.name().parse::<Tag>(), Ok(*tag));
+        assert_eq!(tag.name().to_uppercase().parse::<Tag>(), Ok(*tag));
+        assert!(tag.quotes().all(|q| q.has_tag(*tag)));
+        println!("{}: {} quote(s)", tag, tag.quotes().count());
+    }
+    assert!("nonsense".parse::<Tag>().is_err());
+    Ok(())
+}
+
+#[test]
+fn ratings() {
+    assert!(Rating::Safe < Rating::Controversial && Rating::Controversial < Rating::Crude);
+    assert!(Rating::Safe.quotes().all(|q| q.is_safe()));
+    assert!(Rating::Controversial
+        .at_most()
+        .all(|q| q.rating != Rating::Crude));
+    assert_eq!(by_id(322).map(|q| q.rating), Some(Rating::Controversial));
+    if cfg!(feature = "sfw") {
+        assert_eq!(Rating::Crude.quotes().count(), 0);
+        assert!(by_id(319).is_none());
+    } else {
+        assert_eq!(by_id(319).map(|q| q.rating), Some(Rating::Crude));
+    }
+}
+
+#[test]
+fn author_registry() -> std::result::Result<(), &'static str> {
+    let mut names = std::collections::HashMap::new();
+    for author in AUTHORS.iter() {
+        for name in author.names() {
+            if let Some(other) = names.insert(name.to_lowercase(), &author.name) {
+                println!("{} is used by both {} and {}", name, other, author.name);
+                return Err("Ambiguous author names found");
+            }
+        }
+    }
+    for q in QUOTES.iter() {
+        if !AUTHORS.contains(&q.author) {
+            println!("Quote {}: has unregistered author {}", q.id, q.author);
+            return Err("Unregistered authors found");
+        }
+    }
+    if !cfg!(feature = "sfw") {
+        for author in AUTHORS.iter() {
+            if author.quotes().next().is_none() {
+                println!("{} has no quotes", author);
+                return Err("Authors without quotes found");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn author_lookup() {
+    let dijkstra = Author::find("Dijkstra").unwrap();
+    assert_eq!(dijkstra.name, "Edsger W. Dijkstra");
+    assert_eq!(Author::find("e.w. dijkstra"), Some(dijkstra));
+    assert_eq!(by_author("dijkstra").count(), dijkstra.quotes().count());
+    assert_eq!(by_author("Alan J. Perlis").count(), 15);
+    assert_eq!(
+        Author::find("Johnson").map(|a| &*a.name),
+        Some("John Johnson")
+    );
+    assert_eq!(
+        by_author("Johnson")
+            .map(|q| &q.author.name)
+            .collect::<std::collections::HashSet<_>>()
+            .len(),
+        2
+    );
+    assert!(Author::find("").is_none());
+    assert!(Author::find("Nobody Atall").is_none());
+
+    // Quotes written together are found under each of their authors
+    let thomas = Author::find("Dave Thomas").unwrap();
+    assert_eq!(thomas.name, "David Thomas");
+    assert!(thomas.quotes().all(|q| q.author.members.contains(&thomas)));
+    assert_eq!(by_author("thomas").count(), thomas.quotes().count());
+    let hunt = Author::find("Andy Hunt").unwrap();
+    assert_eq!(hunt.quotes().count(), thomas.quotes().count() + 1);
+    let mut ids: Vec<u32> = search_author("hunt").map(|q| q.id).collect();
+    assert_eq!(ids.len(), hunt.quotes().count());
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), hunt.quotes().count());
+}
+
+#[test]
+fn works() {
+    for q in QUOTES.iter() {
+        if let Some(work) = q.work {
+            assert!(!work.title.is_empty(), "Quote {}: has untitled work", q.id);
+            assert!(
+                !q.author.name.contains(work.title),
+                "Quote {}: has work in author",
+                q.id
+            );
+        }
+    }
+    let clean_code = by_id(199).and_then(|q| q.work).unwrap();
+    assert_eq!(clean_code.kind, WorkKind::Book);
+    assert_eq!(
+        clean_code.to_string(),
+        "Clean Code: A Handbook of Agile Software Craftsmanship (2008)"
+    );
+}
+
+#[test]
+fn author_search() {
+    let torvalds = |query: &str| {
+        search_authors(query)
+            .first()
+            .map(|&(author, m)| (&*author.name, m))
+    };
+    assert_eq!(
+        torvalds("torvalds"),
+        Some(("Linus Torvalds", AuthorMatch::Surname))
+    );
+    assert_eq!(
+        torvalds("LINUS"),
+        Some(("Linus Torvalds", AuthorMatch::Word))
+    );
+    assert_eq!(
+        torvalds("torv"),
+        Some(("Linus Torvalds", AuthorMatch::Substring))
+    );
+    assert_eq!(
+        torvalds("linus torvals"),
+        Some(("Linus Torvalds", AuthorMatch::Fuzzy(1)))
+    );
+    // Alan Perlis has the most quotes of the Alans
+    assert_eq!(search_authors("alan")[0].0.name, "Alan Perlis");
+    assert!(search_authors("kay")
+        .iter()
+        .all(|(author, _)| author.name == "Alan Kay"));
+    assert_eq!(
+        search_author("torvalds").count(),
+        by_author("Linus Torvalds").count()
+    );
+    assert!(search_authors("").is_empty());
+    assert!(search_authors("xyzzy").is_empty());
+    assert_eq!(
+        suggest_author("lines tovalds").map(|a| &*a.name),
+        Some("Linus Torvalds")
+    );
+    assert!(suggest_author("xyzzy").is_none());
+}
+
+#[test]
+fn full_text_search() {
+    let hits = search("Premature Optimization");
+    assert_eq!(hits[0].quote.id, 1);
+    assert!(hits.windows(2).all(|w| w[0].score >= w[1].score));
+    // Plurals match their singular
+    assert!(search("bugs")
+        .iter()
+        .any(|hit| hit.quote.quote.contains("bug.")));
+    assert!(search("the of and").is_empty());
+    assert!(search("").is_empty());
+    assert!(search("xyzzy").is_empty());
+
+    // The index made by build.rs ranks like one made at runtime
+    let index = Index::new(&QUOTES);
+    for query in [
+        "code",
+        "bugs debugging",
+        "premature optimization",
+        "the of and",
+    ] {
+        let ranked = |hits: Vec<Hit>| -> Vec<(u32, u64)> {
+            hits.iter()
+                .map(|hit| (hit.quote.id, hit.score.to_bits()))
+                .collect()
+        };
+        assert_eq!(
+            ranked(search(query)),
+            ranked(index.search(query)),
+            "{}",
+            query
+        );
+    }
+
+    let index = Index::new(&QUOTES[..10]);
+    assert!(index
+        .search("code")
+        .iter()
+        .all(|hit| QUOTES[..10].iter().any(|q| q.id == hit.quote.id)));
+
+    let text = "Debugging is removing bugs";
+    let ranges = match_ranges(text, "bug debugging");
+    let words: Vec<&str> = ranges.into_iter().map(|r| &text[r]).collect();
+    assert_eq!(words, ["Debugging", "bugs"]);
+}
+
+#[test]
+fn toml_strings() {
+    use crate::toml::{parse, Value};
+
+    let string = |src: &str| match parse(src).map(|mut t| t.remove("s")) {
+        Ok(Some(Value::String(s))) => Ok(s),
+        other => Err(format!("{:?}", other)),
+    };
+    // Multi-line strings can end in up to two quotes of their own
+    assert_eq!(
+        string(r#"s = """He said "hi"""""#).unwrap(),
+        "He said \"hi\""
+    );
+    assert_eq!(string(r#"s = """two""""""#).unwrap(), "two\"\"");
+    assert_eq!(string(r#"s = """a ""b"" c""""#).unwrap(), "a \"\"b\"\" c");
+    assert!(string(r#"s = """six is too many"""""""#).is_err());
+    // Literal strings keep backslashes as they are
+    assert_eq!(
+        string("s = '''\nC:\\temp\\ 'n' \"more\"\n'''").unwrap(),
+        "C:\\temp\\ 'n' \"more\"\n"
+    );
+    assert_eq!(string("s = '''It's ''done'''''").unwrap(), "It's ''done''");
+    assert_eq!(string("s = 'C:\\temp'").unwrap(), "C:\\temp");
+    assert_eq!(string("s = ''").unwrap(), "");
+    assert!(string("s = '''never closed''").is_err());
+}
+
+#[test]
+fn quote_book() {
+    let mut book = QuoteBook::new();
+    assert_eq!(book.len(), QUOTES.len());
+    book.load_str(
+        r#"
+        # Built-in quotes to leave out, unknown ids are fine
+        disable = [1, 99999]
+
+        [[quote]]
+        author = "Jane Doe"
+        quote = "Never deploy on a Friday."
+        tags = ["Management", "humor"]
+
+        [[quote]]
+        id = 1
+        author = "donald knuth"
+        quote = """
+        Premature optimization, \"mostly\".\
+        """
+        permalink = 'https://wiki.example.com/retro'
+        rating = "controversial"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(book.len(), QUOTES.len() + 1);
+
+    let max = QUOTES.iter().map(|q| q.id).max().unwrap();
+    let jane = book.by_id(max + 1).unwrap();
+    assert_eq!(jane.author.name, "Jane Doe");
+    assert_eq!(*jane.tags, [Tag::Management, Tag::Humor]);
+    assert_eq!(jane.rating, Rating::Safe);
+    assert!(jane.permalink.is_user());
+    // Loaded quotes and authors are owned by the book, built-in ones borrowed
+    assert!(matches!(jane.quote, Cow::Owned(_)) && matches!(jane.author.name, Cow::Owned(_)));
+    assert!(matches!(book.quotes()[0].quote, Cow::Borrowed(_)));
+
+    let knuth = book.try_by_id(1).unwrap();
+    assert_eq!(knuth.quote, "Premature optimization, \"mostly\".");
+    assert_eq!(knuth.author.name, "Donald Knuth");
+    assert_eq!(
+        knuth.permalink.to_string(),
+        "https://wiki.example.com/retro"
+    );
+    assert!(book.by_author("knuth").any(|q| q.id == 1));
+
+    assert_eq!(book.search_author("jane doe").len(), 1);
+    assert_eq!(
+        book.suggest_author("jane dow").map(|a| &*a.name),
+        Some("Jane Doe")
+    );
+    assert_eq!(book.search("friday")[0].quote.id, max + 1);
+    assert_eq!(book.from_origin(OriginKind::User).count(), 2);
+    assert_eq!(
+        book.tagged(Tag::Humor).count(),
+        Tag::Humor.quotes().count() + 1
+    );
+    assert!(book.seeded(7).is_some());
+    assert!(book.random().is_some());
+
+    // Mistakes leave the book as it was
+    for bad in [
+        "[[quote]]\nid = 7\nauthor = \"x\"\nquote = \"y\"\n[[quote]]\nid = 7\nauthor = \"x\"\nquote = \"z\"",
+        "[[quote]]\nauthor = \"x\"\nquote = \"y\"\ntags = [\"nope\"]",
+        "[[quote]]\nauthor = \"x\"\nquote = \"y\"\ncolour = \"red\"",
+        "[[quote]]\nauthor = \"x\"\nquote = \"y\"\nwork = \"Unwritten\"",
+        "[[quote]]\nauthor = \"x\"",
+        "quote = \"unterminated",
+    ] {
+        assert!(book.load_str(bad).is_err(), "{}", bad);
+    }
+    assert_eq!(book.len(), QUOTES.len() + 1);
+    let error = book.load_str("\n\nx = [1,").unwrap_err().to_string();
+    assert!(error.starts_with("line 3: "), "{}", error);
+
+    assert!(book.disable(max + 1));
+    assert!(!book.disable(max + 1));
+    assert!(book.add(QUOTES[0].clone()).is_err());
+    // A quote with the id of a built-in one takes its place
+    book.load_str("[[quote]]\nid = 2\nauthor = \"x\"\nquote = \"y\"")
+        .unwrap();
+    assert_eq!(book.len(), QUOTES.len());
+    assert_eq!(book.by_id(2).unwrap().quote, "y");
+
+    // The example quote file in the docs of `QuoteBook` loads
+    let docs = include_str!("book.rs");
+    let example: String = docs
+        .lines()
+        .skip_while(|line| *line != "/// ```toml")
+        .skip(1)
+        .take_while(|line| *line != "/// ```")
+        .map(|line| format!("{}\n", line.trim_start_matches("///").trim_start()))
+        .collect();
+    let mut book = QuoteBook::new();
+    book.load_str(&example).unwrap();
+    let brooks = book.by_id(1001).unwrap();
+    assert_eq!(brooks.author.name, "Frederick P. Brooks Jr.");
+    assert_eq!(brooks.work, Some(crate::works::THE_MYTHICAL_MAN_MONTH));
+    assert!(book.by_id(31).is_none());
+}
+
+#[test]
+fn lengths() {
+    let q = by_id(44).unwrap();
+    assert_eq!(q.char_count(), q.quote.chars().count());
+    assert_eq!(q.word_count(), 13);
+    assert_eq!(q.display_width(), q.char_count());
+    assert_eq!(q.line_count(100), 1);
+    assert_eq!(q.line_count(40), 3);
+    assert_eq!(q.line_count(q.display_width()), 1);
+    assert_eq!(q.line_count(q.display_width() - 1), 2);
+
+    // Line breaks count as a space on one line
+    let multi = QUOTES
+        .iter()
+        .find(|q| q.quote.trim().contains('\n'))
+        .unwrap();
+    assert!(multi.line_count(1000) > 1);
+    assert!(multi.display_width() <= multi.char_count());
+
+    let longest = QUOTES.iter().map(|q| q.char_count()).max().unwrap();
+    assert!(longest > 1000);
+    assert_eq!(Query::new().max_len(longest).count(), QUOTES.len());
+    assert_eq!(Query::new().min_len(longest + 1).count(), 0);
+    for q in Query::new().min_len(100).max_len(140).iter() {
+        assert!((100..=140).contains(&q.char_count()));
+    }
+    let tweets = Query::new().max_len(280).count();
+    assert!(tweets > 0 && tweets < QUOTES.len());
+
+    let status_bar = Query::new().max_lines(1, 60);
+    assert!(status_bar.count() > 0);
+    for q in status_bar.iter() {
+        assert!(q.line_count(60) == 1 && q.display_width() <= 60);
+        assert!(wrap(&q.quote, 60).len() == 1);
+    }
+    assert_eq!(
+        Query::new().max_lines(3, 40).count(),
+        QUOTES
+            .iter()
+            .filter(|q| wrap(&q.quote, 40).len() <= 3)
+            .count()
+    );
+}
+
+#[test]
+fn queries() {
+    let ids = |quotes: &mut dyn Iterator<Item = &Quote>| quotes.map(|q| q.id).collect::<Vec<_>>();
+    assert_eq!(Query::new().count(), QUOTES.len());
+    assert_eq!(Query::new().first().map(|q| q.id), Some(QUOTES[0].id));
+
+    let knuth = Query::new().author("knuth");
+    assert_eq!(
+        ids(&mut knuth.iter()),
+        ids(&mut QUOTES.iter().filter(|q| q.author.name == "Donald Knuth"))
+    );
+    assert_eq!(Query::new().author("nobody at all").count(), 0);
+    // An exact name leaves out authors it is only part of the name of
+    assert!(search_authors("RMS")
+        .iter()
+        .any(|(a, m)| a.name == "Joe Armstrong" && *m != AuthorMatch::Exact));
+    assert_eq!(
+        ids(&mut Query::new().author("RMS").iter()),
+        ids(&mut QUOTES
+            .iter()
+            .filter(|q| q.author.name == "Richard Stallman"))
+    );
+
+    let query = Query::new()
+        .origin(OriginKind::VimStartify)
+        .tag(Tag::Testing)
+        .tag(Tag::Debugging)
+        .safe()
+        .max_len(200);
+    let found = ids(&mut query.iter());
+    assert!(!found.is_empty());
+    for q in query.iter() {
+        assert!(q.permalink.is_vimstartify() && q.is_safe());
+        assert!(q.has_tag(Tag::Testing) && q.has_tag(Tag::Debugging));
+        assert!(q.quote.chars().count() <= 200);
+    }
+    assert!(found.contains(&query.random().unwrap().id));
+    assert_eq!(query.count(), found.len());
+
+    let rest = query.clone().exclude_ids(&found[1..]);
+    assert_eq!(ids(&mut rest.iter()), &found[..1]);
+    assert!(rest.exclude_ids(&found[..1]).random().is_none());
+    assert_eq!(
+        Query::new().max_rating(Rating::Controversial).count(),
+        Rating::Controversial.at_most().count()
+    );
+
+    let mut book = QuoteBook::new();
+    book.load_str("[[quote]]\nauthor = \"Donald Knuth\"\nquote = \"Hello, world.\"\n")
+        .unwrap();
+    assert_eq!(book.query(&knuth).count(), knuth.count() + 1);
+    book.load_str("[[quote]]\nauthor = \"Donald Knuth Jr.\"\nquote = \"Hi.\"\n")
+        .unwrap();
+    let donald = Query::new().author("Donald Knuth");
+    assert_eq!(book.query(&donald).count(), knuth.count() + 1);
+    assert_eq!(book.query(&Query::new().author("Knuth Jr")).count(), 1);
+    let user = Query::new().origin(OriginKind::User);
+    assert_eq!(book.query(&user).count(), 2);
+    assert_eq!(user.count(), 0);
+}
+
+#[test]
+fn fortune_files() {
+    let quotes: Vec<_> = QUOTES.to_vec();
+    let text = to_fortune(&quotes);
+    assert!(text.lines().all(|line| line.chars().count() <= 72));
+
+    let index = fortune_index(&text);
+    let field = |i: usize| u32::from_be_bytes([index[i], index[i + 1], index[i + 2], index[i + 3]]);
+    assert_eq!(field(0), 2);
+    assert_eq!(field(4) as usize, quotes.len());
+    assert_eq!(index[20], b'%');
+    assert_eq!(index.len(), 24 + (quotes.len() + 1) * 4);
+    assert_eq!(field(24), 0);
+    assert_eq!(field(index.len() - 4) as usize, text.len());
+    // Every fortune starts right after the `%` line of the one before
+    for i in 1..quotes.len() {
+        assert!(text[..field(24 + i * 4) as usize].ends_with("\n%\n"));
+    }
+
+    let mut book = QuoteBook::new();
+    book.load_fortune_str(&text, "fortunes", Rating::Controversial);
+    let imported = &book.quotes()[QUOTES.len()..];
+    assert_eq!(imported.len(), quotes.len());
+    for (quote, fortune) in quotes.iter().zip(imported) {
+        // Every line of the quote comes back, with its spacing tidied up
+        let lines: Vec<String> = quote
+            .quote
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(lines.join("\n"), fortune.quote);
+        assert_eq!(quote.author.name, fortune.author.name);
+        assert!(fortune.permalink.is_user());
+        assert_eq!(fortune.rating, Rating::Controversial);
+    }
+
+    let mut book = QuoteBook::new();
+    book.load_fortune_str(
+        "No author here.\n%\nWrapped\n  over lines.\nKept apart.\n\n\t\u{2014}Grace Hopper, Interview\n%\n%\n- not an author",
+        "",
+        Rating::Safe,
+    );
+    let imported: Vec<_> = book.quotes()[QUOTES.len()..]
+        .iter()
+        .map(|q| (&*q.author.name, &*q.quote))
+        .collect();
+    assert_eq!(
+        imported,
+        [
+            ("Unknown", "No author here."),
+            ("Grace Hopper", "Wrapped over lines.\nKept apart."),
+            ("Unknown", "- not an author"),
+        ]
+    );
+    assert_eq!(fortune_index("")[4..8], [0, 0, 0, 0]);
+}
+
+#[test]
+fn output_formats() {
+    use crate::Quote;
+
+    let quote = Quote {
+        author: Author {
+            name: Cow::Borrowed("Edsger \"EWD\" Dijkstra"),
+            aliases: &[],
+            members: &[],
+            born: None,
+            died: None,
+            description: None,
+        },
+        work: None,
+        id: 1,
+        quote: Cow::Borrowed("Line one, \"quoted\"\nline\ttwo \\o/"),
+        permalink: Origin::User(Cow::Borrowed("quotes.toml")),
+        tags: Cow::Borrowed(&[Tag::Humor, Tag::Testing]),
+        rating: Rating::Safe,
+    };
+    assert_eq!(
+        Format::Jsonl.quote(&quote),
+        "{\"id\":1,\"author\":\"Edsger \\\"EWD\\\" Dijkstra\",\"work\":null,\"year\":null,\
+         \"quote\":\"Line one, \\\"quoted\\\"\\nline\\ttwo \\\\o/\",\"origin\":\"user\",\
+         \"permalink\":\"quotes.toml\",\"tags\":[\"humor\",\"testing\"],\"rating\":\"safe\"}\n"
+    );
+    assert_eq!(
+        Format::Csv.quote(&quote),
+        "id,author,work,year,quote,origin,permalink,tags,rating\n\
+         1,\"Edsger \"\"EWD\"\" Dijkstra\",,,\"Line one, \"\"quoted\"\"\nline\ttwo \\o/\",\
+         user,quotes.toml,\"humor,testing\",safe\n"
+    );
+    assert_eq!(
+        Format::Tsv.quote(&quote).lines().nth(1),
+        Some("1\tEdsger \"EWD\" Dijkstra\t\t\tLine one, \"quoted\"\\nline\\ttwo \\\\o/\tuser\tquotes.toml\thumor,testing\tsafe")
+    );
+    assert!(Format::Yaml
+        .quote(&quote)
+        .contains("\nquote: \"Line one, \\\"quoted\\\"\\nline\\ttwo \\\\o/\"\n"));
+
+    // A `[[quote]]` table per quote, that loads back into a book
+    let epigrams: Vec<Quote> = by_author("Alan Perlis").cloned().collect();
+    let toml = Format::Toml.quotes(&epigrams);
+    assert!(toml.contains("work = \"Epigrams on Programming\"\nquote = "));
+    assert_eq!(toml.matches("[[quote]]").count(), epigrams.len());
+    // Every origin, and a quote of a file of your own
+    let mut exported = epigrams.clone();
+    for kind in OriginKind::ALL.iter().copied() {
+        let first = QUOTES
+            .iter()
+            .find(|q| q.permalink.kind() == kind && exported.iter().all(|e| e.id != q.id));
+        exported.extend(first.cloned());
+    }
+    exported.push(Quote {
+        id: 9999,
+        ..quote.clone()
+    });
+    let mut book = QuoteBook::new();
+    book.load_str(&Format::Toml.quotes(&exported)).unwrap();
+    assert_eq!(book.len(), QUOTES.len() + 1);
+    for q in &exported {
+        let loaded = book.by_id(q.id).unwrap();
+        assert_eq!(loaded.author.name, q.author.name);
+        assert_eq!(loaded.work, q.work);
+        assert_eq!(loaded.quote, q.quote);
+        assert_eq!(loaded.permalink.kind(), q.permalink.kind());
+        assert_eq!(loaded.permalink.to_string(), q.permalink.to_string());
+        assert_eq!(loaded.tags, q.tags);
+        assert_eq!(loaded.rating, q.rating);
+    }
+
+    let json = Format::Json.quotes(&epigrams);
+    assert!(json.starts_with("[\n  {\n    \"id\": "));
+    assert!(json.ends_with("\n  }\n]\n"));
+    assert_eq!(Format::Json.quotes(&[]), "[]\n");
+    assert_eq!(
+        Format::Jsonl.quotes(&epigrams).lines().count(),
+        epigrams.len()
+    );
+    assert!(Format::Yaml.quotes(&epigrams).starts_with("- id: "));
+
+    assert_eq!("YML".parse(), Ok(Format::Yaml));
+    assert_eq!(" ndjson".parse(), Ok(Format::Jsonl));
+    for format in Format::ALL.iter() {
+        assert_eq!(format.name().parse(), Ok(*format));
+    }
+    assert_eq!(
+        "xml".parse::<Format>().unwrap_err().to_string(),
+        "unknown format 'xml', expected one of: csv, json, jsonl, toml, tsv, yaml"
+    );
+}
+
+#[test]
+fn markup() {
+    use crate::{works, Quote};
+
+    let quote = Quote {
+        author: Author {
+            name: Cow::Borrowed("Ada <Countess> & co"),
+            aliases: &[],
+            members: &[],
+            born: None,
+            died: None,
+            description: None,
+        },
+        work: Some(works::EPIGRAMS_ON_PROGRAMMING),
+        id: 1,
+        quote: Cow::Borrowed("- Use `x` *and* <y>\n\"Quoted\" & done"),
+        permalink: Origin::StormConsultancy(1),
+        tags: Cow::Borrowed(&[]),
+        rating: Rating::Safe,
+    };
+    assert_eq!(
+        quote.to_markdown(),
+        "> \\- Use \\`x\\` \\*and\\* \\<y\\>\\\n\
+         > \"Quoted\" & done\n\
+         >\n\
+         > \u{2014} Ada \\<Countess\\> & co, *Epigrams on Programming* (1982) \
+         ([source](http://quotes.stormconsultancy.co.uk/quotes/1))\n"
+    );
+    assert_eq!(
+        quote.to_html(),
+        "<blockquote cite=\"http://quotes.stormconsultancy.co.uk/quotes/1\">\n  \
+         <p>- Use `x` *and* &lt;y&gt;<br>\n  &quot;Quoted&quot; &amp; done</p>\n  \
+         <footer>&mdash; Ada &lt;Countess&gt; &amp; co, \
+         <cite>Epigrams on Programming</cite> (1982)</footer>\n</blockquote>\n"
+    );
+
+    // Quote files have no URL to link to
+    let user = Quote {
+        permalink: Origin::User(Cow::Borrowed("team.toml")),
+        ..quote
+    };
+    assert!(user.to_markdown().ends_with(" (1982) (team.toml)\n"));
+    assert!(user.to_html().starts_with("<blockquote>\n"));
+    // Characters that would end the link early are percent-encoded
+    let wiki = Quote {
+        permalink: Origin::User(Cow::Borrowed("https://en.wikipedia.org/wiki/Ada (x) <y>")),
+        ..user
+    };
+    assert!(wiki
+        .to_markdown()
+        .ends_with("([source](https://en.wikipedia.org/wiki/Ada%20%28x%29%20%3Cy%3E))\n"));
+
+    let quotes: Vec<Quote> = QUOTES.iter().take(40).cloned().collect();
+    let page = html_page(&quotes, "Q&A", GroupBy::Author);
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.contains("<title>Q&amp;A</title>"));
+    assert_eq!(page.matches("<blockquote").count(), quotes.len());
+    assert!(page.ends_with("</body>\n</html>\n"));
+    let headings: Vec<&str> = page
+        .lines()
+        .filter_map(|line| line.strip_suffix("</h2>"))
+        .map(|line| &line[line.find('>').unwrap() + 1..])
+        .collect();
+    let mut sorted = headings.clone();
+    sorted.sort_by_key(|name| name.to_lowercase());
+    assert_eq!(headings, sorted);
+
+    let page = markdown_page(&quotes, "Quotes", GroupBy::Origin);
+    let headings: Vec<&str> = page
+        .lines()
+        .filter_map(|line| line.strip_prefix("## "))
+        .collect();
+    let order: Vec<&str> = OriginKind::ALL
+        .iter()
+        .map(|kind| kind.name())
+        .filter(|name| headings.contains(name))
+        .collect();
+    assert_eq!(headings, order);
+    assert_eq!(page.matches("\n> \u{2014} ").count(), quotes.len());
+}
+
+#[test]
+fn templates() {
+    let render = |template: &str, id: u32| by_id(id).unwrap().render(&template.parse().unwrap());
+
+    assert_eq!(
+        render("{quote} \u{2014} {author} <{link}>", 42),
+        format!(
+            "{} \u{2014} {} <{}>",
+            by_id(42).unwrap().quote,
+            by_id(42).unwrap().author,
+            by_id(42).unwrap().permalink
+        )
+    );
+    assert_eq!(
+        render("#{id} [{tags}] {origin} {rating}", 44),
+        "#44 [complexity] vimstartify safe"
+    );
+
+    let work = "{author}{#work}, {work} ({year}){/work}{^work} (unpublished){/work}";
+    assert_eq!(
+        render(work, 44),
+        "Alan Perlis, Epigrams on Programming (1982)"
+    );
+    assert_eq!(render(work, 60), "John Woods (unpublished)");
+
+    assert_eq!(
+        render("{quote:width=30:indent=2}", 44),
+        "  Fools ignore complexity.\n  Pragmatists suffer it. Some\n  can avoid it. Geniuses\n  remove it."
+    );
+    assert_eq!(render("[{rating:pad=8}]", 44), "[safe    ]");
+    assert_eq!(render("{{id}}\\n{id}\\t\\\\", 44), "{id}\n44\t\\");
+
+    let error = |template: &str| template.parse::<Template>().unwrap_err().to_string();
+    assert_eq!(
+        error("by {writer}"),
+        "column 4: unknown field 'writer', expected one of: \
+         id, quote, author, work, year, origin, link, tags, rating"
+    );
+    assert_eq!(
+        error("{quote:wrap=4}"),
+        "column 1: unknown modifier 'wrap', expected width, indent or pad"
+    );
+    assert_eq!(
+        error("{quote:width=x}"),
+        "column 1: modifier 'width=x' needs a number, like width=4"
+    );
+    assert_eq!(
+        error("{#work} no end"),
+        "column 1: section 'work' is never closed"
+    );
+    assert_eq!(
+        error("{#work}{/year}"),
+        "column 8: '{/year}' without a section to close"
+    );
+    assert_eq!(
+        error("{quote"),
+        "column 1: unclosed '{', use '{{' for a brace"
+    );
+    assert_eq!(
+        error("a } b"),
+        "column 3: unmatched '}', use '}}' for a brace"
+    );
+}
+
+#[test]
+fn frames() {
+    use crate::Quote;
+
+    let program = QUOTES
+        .iter()
+        .find(|q| q.quote.starts_with("Give a man a program"))
+        .unwrap();
+    for frame in Frame::ALL.iter().copied() {
+        let framed = program.framed(frame, 40, 1);
+        assert!(
+            framed.lines().all(|line| line.chars().count() <= 40),
+            "{}",
+            frame
+        );
+        // The second line of the quote starts a line of its own
+        let starts = |text: &str| {
+            framed.lines().any(|line| {
+                line.trim_start_matches(|c| " /|\\│║".contains(c))
+                    .starts_with(text)
+            })
+        };
+        assert!(starts("Give a man") && starts("Teach a man"), "{}", frame);
+        assert!(framed.contains(&*program.author.name));
+        if frame != Frame::Single && frame != Frame::Double && frame != Frame::Rounded {
+            assert!(framed.is_ascii(), "{}", frame);
+        }
+    }
+
+    // ASCII frames leave the text of the quote alone
+    let quote = Quote {
+        quote: Cow::Borrowed("Café — naïve."),
+        ..by_id(44).unwrap().clone()
+    };
+    for frame in [Frame::Ascii, Frame::Bubble, Frame::Banner] {
+        let framed = quote.framed(frame, 40, 1);
+        assert!(framed.contains("Café — naïve."), "{}", frame);
+        assert!(framed.contains("-- "), "{}", frame);
+    }
+
+    let quote = Quote {
+        quote: Cow::Borrowed("Short."),
+        ..by_id(44).unwrap().clone()
+    };
+    assert_eq!(
+        quote.framed(Frame::Ascii, 80, 2),
+        "+--------------------------------------------------+\n\
+         |  Short.                                          |\n\
+         |                                                  |\n\
+         |  -- Alan Perlis, Epigrams on Programming (1982)  |\n\
+         +--------------------------------------------------+\n"
+    );
+    let quote = Quote {
+        work: None,
+        ..quote
+    };
+    assert_eq!(
+        quote.framed(Frame::Rounded, 80, 1).lines().next(),
+        Some("╭───────────────╮")
+    );
+    let banner = quote.framed(Frame::Banner, 20, 0);
+    assert_eq!(
+        banner,
+        "====================\n       Short.\n\n   -- Alan Perlis\n====================\n"
+    );
+
+    // Attributions too wide for the frame wrap, hanging under the name
+    let long = Quote {
+        author: Author {
+            name: Cow::Borrowed("Frederick P. Brooks Jr. and the OS/360 team"),
+            ..quote.author.clone()
+        },
+        work: by_id(44).unwrap().work,
+        ..quote
+    };
+    for frame in Frame::ALL.iter().copied() {
+        let framed = long.framed(frame, 30, 1);
+        assert!(
+            framed.lines().all(|line| display_width(line) <= 30),
+            "{}",
+            framed
+        );
+    }
+    assert_eq!(
+        long.framed(Frame::Ascii, 30, 1),
+        "+----------------------------+\n\
+         | Short.                     |\n\
+         |                            |\n\
+         | -- Frederick P. Brooks Jr. |\n\
+         |    and the OS/360 team,    |\n\
+         |    Epigrams on Programming |\n\
+         |    (1982)                  |\n\
+         +----------------------------+\n"
+    );
+
+    assert_eq!("Cowsay".parse(), Ok(Frame::Bubble));
+    assert_eq!("box".parse(), Ok(Frame::Single));
+    for frame in Frame::ALL.iter() {
+        assert_eq!(frame.name().parse(), Ok(*frame));
+    }
+    assert_eq!(
+        "fancy".parse::<Frame>().unwrap_err().to_string(),
+        "unknown frame 'fancy', expected one of: ascii, banner, bubble, double, rounded, single"
+    );
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(display_width("plain"), 5);
+    assert_eq!(display_width("\u{201c}Quoted\u{201d}"), 8);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("e\u{301}"), 1);
+    assert_eq!(display_width("\u{1f600}!"), 3);
+    // Family of three, joined into one emoji
+    assert_eq!(
+        display_width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"),
+        2
+    );
+
+    assert_eq!(
+        wrap("Talk is cheap. Show me the code.", 15),
+        ["Talk is cheap.", "Show me the", "code."]
+    );
+    // CJK breaks between characters, without adding spaces
+    assert_eq!(wrap("日本語のテキスト", 6), ["日本語", "のテキ", "スト"]);
+    assert_eq!(wrap("Rust は 速い", 8), ["Rust は", "速い"]);
+    assert_eq!(wrap("abcdefghij", 4), ["abcd", "efgh", "ij"]);
+    assert_eq!(wrap("one\n\n  two  ", 10), ["one", "", "two"]);
+    assert!(wrap("", 10).is_empty());
+    for quote in QUOTES.iter() {
+        for line in wrap(&quote.quote, 30) {
+            assert!(display_width(&line) <= 30, "{}", line);
+        }
+    }
+
+    let wrap = Wrap::new(16).justify(true);
+    assert_eq!(
+        wrap.lines("Make it work, make it right, make it fast."),
+        ["Make   it  work,", "make  it  right,", "make it fast."]
+    );
+    assert_eq!(
+        Wrap::new(12)
+            .indent(2)
+            .hanging(3)
+            .fill("aaa bbb ccc ddd eee"),
+        "  aaa bbb\n     ccc ddd\n     eee"
+    );
+    assert_eq!(align_right("\u{201c}x\u{201d}", 5), "  \u{201c}x\u{201d}");
+
+    let perlis = by_id(44).unwrap();
+    let text = perlis.wrapped(&Wrap::new(60));
+    assert!(text.ends_with(&format!(
+        "\n\n{}\u{2014} Alan Perlis, Epigrams on Programming (1982)",
+        " ".repeat(15)
+    )));
+    assert!(text.lines().all(|line| display_width(line) <= 60));
+    // Too long to align, so it hangs under the name instead
+    let text = perlis.wrapped(&Wrap::new(30));
+    assert!(text.ends_with("\n\n\u{2014} Alan Perlis, Epigrams on\n  Programming (1982)"));
+}
+
+#[test]
+fn startify_import() {
+    let vim = r#"
+" vim: et sw=2 sts=2
+let s:cow = [
+      \ '       o',
+      \ '        o   ^__^',
+      \ ]
+
+let s:quotes = exists('g:startify_custom_header_quotes') " user's quotes win
+      \ ? g:startify_custom_header_quotes
+      \ : startify#fortune#predefined_quotes()
+
+let s:predefined_quotes = [
+      \ ["What one programmer can do in one month, two programmers can do in two months.", '', '- Fred Brooks'],
+      "\ Comments can sit between entries
+      \ ['A LISP programmer knows the value of everything, but the cost of nothing!', '', '- Alan Perlis'],
+      \ ["Give a man a program, frustrate him for a day.",
+      \  "Teach a man to program, frustrate him for a lifetime.", '', '- Muhammad Waseem'],
+      \ ["Don't \"quote\" me,", 'it''s [fine].', '', '- Someone New'],
+      \ ["No author at all."],
+      \ ]
+"#;
+    let quotes = parse_startify(vim).unwrap();
+    let read: Vec<_> = quotes
+        .iter()
+        .map(|q| (q.id, &*q.author.name, &*q.quote))
+        .collect();
+    assert_eq!(
+        read,
+        [
+            (1, "Frederick P. Brooks Jr.", "What one programmer can do in one month, two programmers can do in two months."),
+            (2, "Alan Perlis", "A LISP programmer knows the value of everything, but the cost of nothing!"),
+            (3, "Muhammad Waseem", "Give a man a program, frustrate him for a day.\nTeach a man to program, frustrate him for a lifetime."),
+            (4, "Someone New", "Don't \"quote\" me,\nit's [fine]."),
+            (5, "Unknown", "No author at all."),
+        ]
+    );
+    assert!(quotes.iter().all(|q| q.permalink.is_vimstartify()));
+
+    let diff = diff_startify(&quotes);
+    assert_eq!(diff.unchanged, 2);
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(
+        (diff.changed[0].0.id, &*diff.changed[0].1.author.name),
+        (2, "Alan Perlis")
+    );
+    assert_eq!(
+        diff.missing.iter().map(|q| q.id).collect::<Vec<_>>(),
+        [4, 5]
+    );
+    let ours = QUOTES
+        .iter()
+        .filter(|q| q.permalink.is_vimstartify())
+        .count();
+    assert_eq!(diff.removed.len(), ours - 2);
+    assert!(diff.to_string().starts_with(&format!(
+        "2 unchanged, 2 missing, 1 changed, {} removed",
+        ours - 2
+    )));
+
+    assert!(parse_startify("let x = 1").is_err());
+    let error = parse_startify("let s:q = [\n      \\ ['broken],\n      \\ ]").unwrap_err();
+    assert_eq!(error.to_string(), "line 2: unterminated string");
+}
+
+#[test]
+fn themes() {
+    let env = |vars: &'static [(&'static str, &'static str)]| {
+        move |key: &str| {
+            vars.iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.to_string())
+        }
+    };
+    let auto = ColorChoice::Auto;
+    assert_eq!(auto.depth_with(true, env(&[])), ColorDepth::Ansi16);
+    assert_eq!(auto.depth_with(false, env(&[])), ColorDepth::None);
+    assert_eq!(
+        auto.depth_with(true, env(&[("NO_COLOR", "1")])),
+        ColorDepth::None
+    );
+    assert_eq!(
+        auto.depth_with(true, env(&[("NO_COLOR", "")])),
+        ColorDepth::Ansi16
+    );
+    assert_eq!(
+        auto.depth_with(true, env(&[("TERM", "dumb")])),
+        ColorDepth::None
+    );
+    assert_eq!(
+        auto.depth_with(true, env(&[("CLICOLOR", "0")])),
+        ColorDepth::None
+    );
+    let forced = env(&[("CLICOLOR_FORCE", "1"), ("TERM", "xterm-256color")]);
+    assert_eq!(auto.depth_with(false, forced), ColorDepth::Ansi256);
+    let truecolor = env(&[("COLORTERM", "truecolor"), ("NO_COLOR", "1")]);
+    assert_eq!(auto.depth_with(true, truecolor), ColorDepth::None);
+    assert_eq!(
+        ColorChoice::Always.depth_with(false, truecolor),
+        ColorDepth::TrueColor
+    );
+    assert_eq!(
+        ColorChoice::Never.depth_with(true, env(&[])),
+        ColorDepth::None
+    );
+    assert_eq!("ALWAYS".parse(), Ok(ColorChoice::Always));
+    assert!("sometimes".parse::<ColorChoice>().is_err());
+
+    let orange = Color::Rgb(0xff, 0x87, 0x00);
+    assert_eq!(orange.downgrade(ColorDepth::TrueColor), Some(orange));
+    assert_eq!(
+        orange.downgrade(ColorDepth::Ansi256),
+        Some(Color::Fixed(208))
+    );
+    assert_eq!(orange.downgrade(ColorDepth::Ansi16), Some(Color::Ansi(3)));
+    assert_eq!(orange.downgrade(ColorDepth::None), None);
+    let grey = Color::Rgb(0x80, 0x80, 0x80);
+    assert_eq!(grey.downgrade(ColorDepth::Ansi256), Some(Color::Fixed(244)));
+    assert_eq!(
+        Color::Fixed(21).downgrade(ColorDepth::Ansi16),
+        Some(Color::Ansi(4))
+    );
+
+    let style: Style = "bold bright-red".parse().unwrap();
+    assert_eq!(style.color, Some(Color::Ansi(9)));
+    assert_eq!(
+        style.paint("a\n\nb", ColorDepth::Ansi16),
+        "\x1b[1;91ma\x1b[0m\n\n\x1b[1;91mb\x1b[0m"
+    );
+    assert_eq!(style.paint("a", ColorDepth::None), "a");
+    let style: Style = "underline #ff8700".parse().unwrap();
+    assert_eq!(
+        style.paint("a", ColorDepth::Ansi256),
+        "\x1b[4;38;5;208ma\x1b[0m"
+    );
+    assert_eq!("none".parse(), Ok(Style::default()));
+    let error = "bold #ff87".parse::<Style>().unwrap_err();
+    assert!(error.to_string().starts_with("unknown style '#ff87'"));
+
+    for name in Theme::BUILTIN.iter() {
+        assert!(Theme::builtin(name).is_some(), "{}", name);
+    }
+    assert_eq!(Theme::builtin("plain"), Some(Theme::default()));
+
+    let mut themes = Themes::new();
+    assert_eq!(themes.default_theme(), Theme::builtin("default").unwrap());
+    themes
+        .load_str("theme = \"sunset\"\n\n[sunset]\nauthor = \"bold #ff8700\"\nid = \"dim\"\n")
+        .unwrap();
+    let sunset = themes.get("sunset").unwrap();
+    assert_eq!(themes.default_theme(), sunset);
+    assert_eq!(sunset.quote, Style::default());
+    assert!(sunset.id.dim);
+    assert_eq!(themes.names()[0], "default");
+    assert!(themes.names().contains(&"sunset"));
+
+    let error = themes.load_str("[dusk]\nauthr = \"bold\"\n").unwrap_err();
+    assert!(error.to_string().contains("unknown key 'authr'"));
+    let error = themes.load_str("theme = \"dusk\"\n").unwrap_err();
+    assert_eq!(error.to_string(), "unknown theme 'dusk'");
+    assert!(themes.get("dusk").is_none());
+}
+
+#[test]
+fn rotation() {
+    let quotes: Vec<Quote> = Tag::Testing.quotes().cloned().collect();
+    let mut state = 7_u64;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        state
+    };
+
+    // Every quote once per round, and no quote twice in a row between rounds
+    let mut rotation = Rotation::new();
+    let mut last = None;
+    for _ in 0..3 {
+        let mut round: Vec<u32> = Vec::new();
+        for _ in 0..quotes.len() {
+            let id = rotation.next_with(&quotes, &mut next).unwrap().id;
+            assert_ne!(Some(id), last);
+            last = Some(id);
+            round.push(id);
+        }
+        round.sort_unstable();
+        round.dedup();
+        assert_eq!(round.len(), quotes.len());
+    }
+    assert!(Rotation::new().next(&[]).is_none());
+
+    // Quotes of other rounds keep their place when a round starts over
+    let mut rotation = Rotation::new();
+    let first = rotation.next_with(&quotes[..1], &mut next).unwrap().id;
+    let other: Vec<Quote> = quotes[1..3].to_vec();
+    for _ in 0..3 {
+        rotation.next_with(&other, &mut next);
+    }
+    assert!(rotation.shown().contains(&first));
+    assert_eq!(rotation.shown().len(), 2);
+
+    let text = rotation.to_string();
+    assert!(text.contains(&format!("shown = [{}, ", first)));
+    assert_eq!(text.parse::<Rotation>(), Ok(rotation.clone()));
+    assert_eq!("".parse::<Rotation>(), Ok(Rotation::new()));
+    let error = "shown = [1, -2]".parse::<Rotation>().unwrap_err();
+    assert_eq!(error.to_string(), "-2 is not the id of a quote");
+    assert!("shown = [1".parse::<Rotation>().is_err());
+
+    let dir = std::env::temp_dir().join(format!("pquote-rotation-{}", std::process::id()));
+    let path = dir.join("state").join("rotation.toml");
+    assert_eq!(Rotation::load(&path).unwrap(), Rotation::new());
+    rotation.save(&path).unwrap();
+    assert_eq!(Rotation::load(&path).unwrap(), rotation);
+    std::fs::write(&path, "shown = 12").unwrap();
+    assert!(Rotation::load(&path).is_err());
+    let mut ids: Vec<u32> = (0..quotes.len())
+        .map(|_| Rotation::next_in_file(&path, &quotes).unwrap().unwrap().id)
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), quotes.len());
+    // A lock left behind by a process that died is cleared, not waited out
+    let lock = std::fs::File::create(path.with_extension("toml.lock")).unwrap();
+    let an_hour_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+    lock.set_modified(an_hour_ago).unwrap();
+    assert!(Rotation::next_in_file(&path, &quotes).unwrap().is_some());
+    let left: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(left, ["rotation.toml"]);
+    // Two processes finding the same stale lock don't both take the file
+    let lock = std::fs::File::create(path.with_extension("toml.lock")).unwrap();
+    lock.set_modified(an_hour_ago).unwrap();
+    let start = std::sync::Barrier::new(2);
+    let ids: Vec<u32> = std::thread::scope(|s| {
+        let threads: Vec<_> = (0..2)
+            .map(|_| {
+                s.spawn(|| {
+                    start.wait();
+                    Rotation::next_in_file(&path, &quotes).unwrap().unwrap().id
+                })
+            })
+            .collect();
+        threads.into_iter().map(|t| t.join().unwrap()).collect()
+    });
+    assert_ne!(ids[0], ids[1]);
+    let left: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(left, ["rotation.toml"]);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    use crate::QuoteBuf;
+
+    for q in QUOTES.iter() {
+        let json = serde_json::to_string(q).unwrap();
+        let owned: QuoteBuf = serde_json::from_str(&json).unwrap();
+        assert_eq!(owned, QuoteBuf::from(q), "{}", json);
+    }
+
+    let storm = QUOTES
+        .iter()
+        .find(|q| q.permalink.kind() == OriginKind::StormConsultancy)
+        .unwrap();
+    let json = serde_json::to_string(storm).unwrap();
+    assert!(json.contains(&format!("\"author\":\"{}\"", storm.author.name)));
+    assert!(json.contains(&format!(
+        "\"permalink\":{{\"kind\":\"stormconsultancy\",\"url\":\"{}\"}}",
+        storm.permalink
+    )));
+
+    let epigram = by_id(44).unwrap();
+    let owned = QuoteBuf::from(epigram);
+    assert_eq!(owned.work.unwrap().title, "Epigrams on Programming");
+    assert_eq!(owned.permalink.kind, OriginKind::VimStartify);
+}
@@ -0,0 +1,564 @@
+use crate::book::{config_dir, LoadError};
+use crate::toml::{self, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Colours a terminal can show, from none at all to 24-bit colour
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// No escape codes at all, e.g. when output is piped
+    None,
+    /// The 16 standard colours
+    Ansi16,
+    /// The xterm 256 colour palette
+    Ansi256,
+    /// 24-bit RGB colour
+    TrueColor,
+}
+
+/// Whether to colour output, as in `--color=auto|always|never`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Colour terminals, unless `NO_COLOR` is set or `CLICOLOR` is `0`
+    Auto,
+    /// Colour even when not writing to a terminal
+    Always,
+    /// Never colour
+    Never,
+}
+
+/// Colour of text
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// One of the 16 standard colours, `0..=7` and their bright versions `8..=15`
+    Ansi(u8),
+    /// Colour of the xterm 256 colour palette
+    Fixed(u8),
+    /// 24-bit colour
+    Rgb(u8, u8, u8),
+}
+
+/// How a part of a quote looks, e.g. `"bold #ff8800"`
+///
+/// Parsed from words separated by spaces: `bold`, `dim`, `italic` and
+/// `underline`, plus a colour, which is either a name like `red` or
+/// `bright-blue`, a number of the 256 colour palette or `#rrggbb`. `none`
+/// is no style at all.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub color: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// Styles of the parts of a quote
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Theme {
+    pub quote: Style,
+    pub author: Style,
+    pub link: Style,
+    pub id: Style,
+}
+
+/// Names of the 8 standard colours, in palette order
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// RGB values of the 16 standard colours, as xterm shows them
+const ANSI_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Levels of each channel in the 6x6x6 colour cube of the 256 colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Built-in themes and the styles of their quote, author, link and id
+const BUILTIN: [(&str, [&str; 4]); 5] = [
+    ("default", ["none", "bold cyan", "underline blue", "dim"]),
+    (
+        "gruvbox",
+        ["#ebdbb2", "bold #fabd2f", "underline #83a598", "#928374"],
+    ),
+    ("mono", ["italic", "bold", "underline", "dim"]),
+    ("plain", ["none", "none", "none", "none"]),
+    (
+        "solarized",
+        ["#839496", "bold #b58900", "underline #268bd2", "#586e75"],
+    ),
+];
+
+impl ColorChoice {
+    /// Colours to use for output to a terminal or not, going by `NO_COLOR`,
+    /// `CLICOLOR`, `CLICOLOR_FORCE`, `TERM` and `COLORTERM`
+    ///
+    /// `Always` wins over `NO_COLOR`, like any command line flag should.
+    #[must_use]
+    pub fn depth(self, is_terminal: bool) -> ColorDepth {
+        self.depth_with(is_terminal, |key| std::env::var(key).ok())
+    }
+
+    pub(crate) fn depth_with(
+        self,
+        is_terminal: bool,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> ColorDepth {
+        let set = |key| env(key).filter(|value| !value.is_empty());
+        let forced = set("CLICOLOR_FORCE").map_or(false, |value| value != "0");
+        let colour = match self {
+            ColorChoice::Never => false,
+            ColorChoice::Always => true,
+            ColorChoice::Auto if set("NO_COLOR").is_some() => false,
+            ColorChoice::Auto if forced => true,
+            ColorChoice::Auto => {
+                is_terminal
+                    && set("CLICOLOR").as_deref() != Some("0")
+                    && set("TERM").as_deref() != Some("dumb")
+            }
+        };
+        if !colour {
+            return ColorDepth::None;
+        }
+        let term = set("TERM").unwrap_or_default();
+        match set("COLORTERM").as_deref() {
+            Some("truecolor") | Some("24bit") => ColorDepth::TrueColor,
+            _ if term.contains("256color") => ColorDepth::Ansi256,
+            _ => ColorDepth::Ansi16,
+        }
+    }
+}
+
+impl Color {
+    /// The closest colour a terminal with `depth` can show, `None` if it
+    /// shows no colour at all
+    #[must_use]
+    pub fn downgrade(self, depth: ColorDepth) -> Option<Color> {
+        match (depth, self) {
+            (ColorDepth::None, _) => None,
+            (ColorDepth::TrueColor, _) | (_, Color::Ansi(_)) => Some(self),
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Some(Color::Fixed(rgb_to_fixed(r, g, b))),
+            (ColorDepth::Ansi256, Color::Fixed(_)) => Some(self),
+            (ColorDepth::Ansi16, Color::Fixed(n)) if n < 16 => Some(Color::Ansi(n)),
+            (ColorDepth::Ansi16, Color::Fixed(n)) => {
+                let (r, g, b) = fixed_to_rgb(n);
+                Some(Color::Ansi(rgb_to_ansi(r, g, b)))
+            }
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => Some(Color::Ansi(rgb_to_ansi(r, g, b))),
+        }
+    }
+
+    /// Parameters of the escape code setting this as the text colour
+    fn code(self) -> String {
+        match self {
+            Color::Ansi(n) if n < 8 => (30 + n).to_string(),
+            Color::Ansi(n) => (90 + (n & 7)).to_string(),
+            Color::Fixed(n) => format!("38;5;{}", n),
+            Color::Rgb(r, g, b) => format!("38;2;{};{};{}", r, g, b),
+        }
+    }
+}
+
+impl Style {
+    /// Text with escape codes for the style, the colour downgraded to
+    /// `depth`, or the text as is for [`ColorDepth::None`]
+    ///
+    /// Every line is styled on its own, so pagers and frames keep the style.
+    #[must_use]
+    pub fn paint(&self, text: &str, depth: ColorDepth) -> String {
+        let mut codes: Vec<String> = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+        ]
+        .iter()
+        .filter(|(on, _)| *on)
+        .map(|(_, code)| code.to_string())
+        .collect();
+        codes.extend(self.color.and_then(|c| c.downgrade(depth)).map(Color::code));
+        if depth == ColorDepth::None || codes.is_empty() {
+            return text.to_string();
+        }
+        let start = format!("\x1b[{}m", codes.join(";"));
+        text.split('\n')
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}{}\x1b[0m", start, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Theme {
+    /// Names of the built-in themes, in alphabetical order
+    pub const BUILTIN: [&'static str; 5] = [
+        BUILTIN[0].0,
+        BUILTIN[1].0,
+        BUILTIN[2].0,
+        BUILTIN[3].0,
+        BUILTIN[4].0,
+    ];
+
+    /// Built-in theme with this name, see [`Theme::BUILTIN`]
+    #[must_use]
+    pub fn builtin(name: &str) -> Option<Theme> {
+        let (_, styles) = BUILTIN.iter().find(|(builtin, _)| *builtin == name)?;
+        let style = |i: usize| styles[i].parse().unwrap_or_default();
+        Some(Theme {
+            quote: style(0),
+            author: style(1),
+            link: style(2),
+            id: style(3),
+        })
+    }
+}
+
+/// Where `pq` looks for your own themes, `themes.toml` next to [`user_file`](crate::user_file)
+#[must_use]
+pub fn theme_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("themes.toml"))
+}
+
+/// Built-in themes and your own, by name
+///
+/// Themes are read from TOML files with a table of styles per theme, and
+/// optionally the theme to use by default:
+///
+/// ```toml
+/// theme = "sunset"
+///
+/// [sunset]
+/// quote = "italic"
+/// author = "bold #ff8800"
+/// link = "underline 33"
+/// id = "dim"
+/// ```
+///
+/// Styles left out are plain, see [`Style`] for what they look like. Your
+/// own themes win over built-in ones of the same name.
+#[derive(Debug, Clone, Default)]
+pub struct Themes {
+    default: Option<String>,
+    themes: BTreeMap<String, Theme>,
+}
+
+impl Themes {
+    /// Just the built-in themes
+    #[must_use]
+    pub fn new() -> Self {
+        Themes::default()
+    }
+
+    /// Built-in themes plus the ones in [`theme_file`], if it exists
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the file can't be read or is invalid.
+    pub fn with_user_file() -> Result<Self, LoadError> {
+        let mut themes = Themes::new();
+        if let Some(path) = theme_file().filter(|path| path.is_file()) {
+            themes.load(path)?;
+        }
+        Ok(themes)
+    }
+
+    /// Add the themes of a file, see [`Themes`] for its format
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the file can't be read or is invalid.
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        let result = match std::fs::read_to_string(path) {
+            Ok(text) => self.load_str(&text),
+            Err(e) => Err(LoadError {
+                path: None,
+                line: None,
+                message: e.to_string(),
+            }),
+        };
+        result.map_err(|e| LoadError {
+            path: Some(path.to_path_buf()),
+            ..e
+        })
+    }
+
+    /// Add the themes of a TOML string, see [`Themes`] for its format
+    ///
+    /// Nothing is added if the string has any mistake in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError`] if the string is invalid.
+    pub fn load_str(&mut self, text: &str) -> Result<(), LoadError> {
+        let error = |message: String| LoadError {
+            path: None,
+            line: None,
+            message,
+        };
+        let root = toml::parse(text).map_err(|e| LoadError {
+            path: None,
+            line: Some(e.line),
+            message: e.message,
+        })?;
+
+        let mut themes = self.clone();
+        for (name, value) in root {
+            match (name.as_str(), value) {
+                ("theme", Value::String(default)) => themes.default = Some(default),
+                ("theme", other) => {
+                    return Err(error(format!(
+                        "'theme' must be a string, not {}",
+                        other.type_name()
+                    )))
+                }
+                (_, Value::Table(table)) => {
+                    let mut theme = Theme::default();
+                    for (key, value) in table {
+                        let style = match key.as_str() {
+                            "quote" => &mut theme.quote,
+                            "author" => &mut theme.author,
+                            "link" => &mut theme.link,
+                            "id" => &mut theme.id,
+                            _ => {
+                                return Err(error(format!(
+                                    "theme '{}': unknown key '{}', {}",
+                                    name, key, "expected quote, author, link or id"
+                                )))
+                            }
+                        };
+                        *style = match value {
+                            Value::String(spec) => spec
+                                .parse()
+                                .map_err(|e| error(format!("theme '{}': {}", name, e)))?,
+                            other => {
+                                return Err(error(format!(
+                                    "theme '{}': '{}' must be a string, not {}",
+                                    name,
+                                    key,
+                                    other.type_name()
+                                )))
+                            }
+                        };
+                    }
+                    themes.themes.insert(name, theme);
+                }
+                (_, other) => {
+                    return Err(error(format!(
+                        "theme '{}' must be a table, not {}",
+                        name,
+                        other.type_name()
+                    )))
+                }
+            }
+        }
+        if let Some(default) = &themes.default {
+            if themes.get(default).is_none() {
+                return Err(error(format!("unknown theme '{}'", default)));
+            }
+        }
+        *self = themes;
+        Ok(())
+    }
+
+    /// Theme with this name, your own or built-in
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Theme> {
+        self.themes
+            .get(name)
+            .copied()
+            .or_else(|| Theme::builtin(name))
+    }
+
+    /// Names of every theme, built-in ones included, in alphabetical order
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.themes.keys().map(String::as_str).collect();
+        names.extend(Theme::BUILTIN.iter().copied());
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    /// Theme chosen with `theme = "..."`, else the built-in `default` theme
+    #[must_use]
+    pub fn default_theme(&self) -> Theme {
+        self.default
+            .as_deref()
+            .and_then(|name| self.get(name))
+            .or_else(|| Theme::builtin("default"))
+            .unwrap_or_default()
+    }
+}
+
+/// Error returned when parsing a [`Style`] with a word that is not a style
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStyleError {
+    word: String,
+}
+
+impl std::fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown style '{}', expected bold, dim, italic, underline, a colour name, 0-255 or #rrggbb",
+            self.word
+        )
+    }
+}
+
+impl std::error::Error for ParseStyleError {}
+
+impl std::str::FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::default();
+        for word in s.split_whitespace() {
+            let lower = word.to_lowercase();
+            match lower.as_str() {
+                "none" | "plain" => {}
+                "bold" => style.bold = true,
+                "dim" => style.dim = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                _ => {
+                    style.color = Some(parse_color(&lower).ok_or_else(|| ParseStyleError {
+                        word: word.to_string(),
+                    })?)
+                }
+            }
+        }
+        Ok(style)
+    }
+}
+
+fn parse_color(word: &str) -> Option<Color> {
+    if let Some(hex) = word.strip_prefix('#') {
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return if hex.len() == 6 {
+            Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+        } else {
+            None
+        };
+    }
+    if let Ok(n) = word.parse() {
+        return Some(Color::Fixed(n));
+    }
+    if word == "gray" || word == "grey" {
+        return Some(Color::Ansi(8));
+    }
+    let (bright, name) = match word
+        .strip_prefix("bright-")
+        .or_else(|| word.strip_prefix("bright"))
+    {
+        Some(name) => (8, name),
+        None => (0, word),
+    };
+    let n = COLOR_NAMES.iter().position(|color| *color == name)?;
+    Some(Color::Ansi(bright + n as u8))
+}
+
+/// RGB value of a colour of the 256 colour palette
+fn fixed_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => ANSI_RGB[usize::from(n)],
+        16..=231 => {
+            let i = usize::from(n - 16);
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[i / 6 % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        _ => {
+            let level = 8 + (n - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+/// Closest colour of the 256 colour palette, from its cube or grey ramp
+fn rgb_to_fixed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (v - 35) / 40,
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let grey = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+    if distance(fixed_to_rgb(grey), (r, g, b)) < distance(fixed_to_rgb(cube), (r, g, b)) {
+        grey
+    } else {
+        cube
+    }
+}
+
+/// Closest of the 16 standard colours
+fn rgb_to_ansi(r: u8, g: u8, b: u8) -> u8 {
+    (0..16)
+        .min_by_key(|&n| distance(ANSI_RGB[usize::from(n)], (r, g, b)))
+        .unwrap_or(7)
+}
+
+/// Parse from `auto`, `always` or `never`
+impl std::str::FromStr for ColorChoice {
+    type Err = ParseColorChoiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(ParseColorChoiceError {
+                name: s.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Error returned when parsing a [`ColorChoice`] that is not `auto`,
+/// `always` or `never`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorChoiceError {
+    name: String,
+}
+
+impl std::fmt::Display for ParseColorChoiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown color choice '{}', expected auto, always or never",
+            self.name
+        )
+    }
+}
+
+impl std::error::Error for ParseColorChoiceError {}
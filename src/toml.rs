@@ -0,0 +1,373 @@
+//! Just enough [TOML](https://toml.io) to read quote files: tables, arrays of
+//! tables, strings, integers, booleans and arrays. Dotted keys, floats, dates
+//! and inline tables are not supported.
+//!
+//! Also compiled into `build.rs`, so it must not use anything from the crate.
+
+use std::collections::BTreeMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Keys and values of a table
+pub(crate) type Table = BTreeMap<String, Value>;
+
+/// Any value this parser understands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<Value>),
+    Table(Table),
+}
+
+impl Value {
+    /// Name of the type of the value, for error messages
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Integer(_) => "integer",
+            Value::Boolean(_) => "boolean",
+            Value::Array(_) => "array",
+            Value::Table(_) => "table",
+        }
+    }
+}
+
+/// Error returned when a document is not valid (or not supported) TOML
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parse a document into its root table
+pub(crate) fn parse(src: &str) -> Result<Table, Error> {
+    Parser {
+        chars: src.chars().peekable(),
+        line: 1,
+    }
+    .document()
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl Parser<'_> {
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, Error> {
+        Err(Error {
+            line: self.line,
+            message: message.into(),
+        })
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.chars.peek() == Some(&c) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.error(format!("expected '{}'", c))
+        }
+    }
+
+    /// Skip spaces and tabs, and a comment up to the end of the line
+    fn skip_line_space(&mut self) {
+        while self.eat(' ') || self.eat('\t') {}
+        if self.chars.peek() == Some(&'#') {
+            while !matches!(self.chars.peek(), None | Some('\n')) {
+                self.next();
+            }
+        }
+    }
+
+    /// Skip whitespace, newlines and comments
+    fn skip_space(&mut self) {
+        loop {
+            self.skip_line_space();
+            if !(self.eat('\n') || self.eat('\r')) {
+                break;
+            }
+        }
+    }
+
+    fn end_of_line(&mut self) -> Result<(), Error> {
+        self.skip_line_space();
+        self.eat('\r');
+        if self.chars.peek().is_none() || self.eat('\n') {
+            Ok(())
+        } else {
+            self.error("expected the end of the line")
+        }
+    }
+
+    fn document(&mut self) -> Result<Table, Error> {
+        let mut root = Table::new();
+        // Name of the last `[table]` or `[[table]]` header, if any
+        let mut current: Option<String> = None;
+        loop {
+            self.skip_space();
+            match self.chars.peek() {
+                None => return Ok(root),
+                Some('[') => {
+                    self.next();
+                    let array = self.eat('[');
+                    self.skip_line_space();
+                    let name = self.key()?;
+                    self.skip_line_space();
+                    self.expect(']')?;
+                    if array {
+                        self.expect(']')?;
+                    }
+                    self.end_of_line()?;
+                    match (root.get_mut(&name), array) {
+                        (None, false) => {
+                            root.insert(name.clone(), Value::Table(Table::new()));
+                        }
+                        (None, true) => {
+                            root.insert(
+                                name.clone(),
+                                Value::Array(vec![Value::Table(Table::new())]),
+                            );
+                        }
+                        (Some(Value::Array(tables)), true) => {
+                            tables.push(Value::Table(Table::new()))
+                        }
+                        _ => return self.error(format!("'{}' is defined twice", name)),
+                    }
+                    current = Some(name);
+                }
+                Some(_) => {
+                    let key = self.key()?;
+                    self.skip_line_space();
+                    self.expect('=')?;
+                    self.skip_line_space();
+                    let value = self.value()?;
+                    self.end_of_line()?;
+                    let table = match current.as_ref().and_then(|name| root.get_mut(name)) {
+                        Some(Value::Table(table)) => table,
+                        Some(Value::Array(tables)) => match tables.last_mut() {
+                            Some(Value::Table(table)) => table,
+                            _ => unreachable!("arrays of tables only hold tables"),
+                        },
+                        _ => &mut root,
+                    };
+                    if table.contains_key(&key) {
+                        return self.error(format!("'{}' is defined twice", key));
+                    }
+                    table.insert(key, value);
+                }
+            }
+        }
+    }
+
+    fn key(&mut self) -> Result<String, Error> {
+        if self.eat('"') {
+            return self.basic_string();
+        }
+        let mut key = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                key.push(c);
+                self.next();
+            } else {
+                break;
+            }
+        }
+        match self.chars.peek() {
+            _ if key.is_empty() => self.error("expected a key"),
+            Some('.') => self.error("dotted keys are not supported"),
+            _ => Ok(key),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        match self.chars.peek() {
+            Some('"') => {
+                self.next();
+                if self.eat('"') {
+                    if self.eat('"') {
+                        self.multi_line_string('"').map(Value::String)
+                    } else {
+                        Ok(Value::String(String::new()))
+                    }
+                } else {
+                    self.basic_string().map(Value::String)
+                }
+            }
+            Some('\'') => {
+                self.next();
+                if self.eat('\'') {
+                    return if self.eat('\'') {
+                        self.multi_line_string('\'').map(Value::String)
+                    } else {
+                        Ok(Value::String(String::new()))
+                    };
+                }
+                let mut s = String::new();
+                loop {
+                    match self.string_char()? {
+                        '\'' => return Ok(Value::String(s)),
+                        c => s.push(c),
+                    }
+                }
+            }
+            Some('[') => {
+                self.next();
+                let mut values = Vec::new();
+                loop {
+                    self.skip_space();
+                    if self.eat(']') {
+                        return Ok(Value::Array(values));
+                    }
+                    values.push(self.value()?);
+                    self.skip_space();
+                    if !self.eat(',') {
+                        self.skip_space();
+                        self.expect(']')?;
+                        return Ok(Value::Array(values));
+                    }
+                }
+            }
+            Some('t') | Some('f') => {
+                let word = self.word();
+                match word.as_str() {
+                    "true" => Ok(Value::Boolean(true)),
+                    "false" => Ok(Value::Boolean(false)),
+                    _ => self.error(format!("unexpected '{}'", word)),
+                }
+            }
+            Some(c) if c.is_ascii_digit() || *c == '-' || *c == '+' => {
+                let word = self.word();
+                match word.replace('_', "").parse() {
+                    Ok(n) if !word.starts_with('_') && !word.ends_with('_') => {
+                        Ok(Value::Integer(n))
+                    }
+                    _ => self.error(format!("'{}' is not a supported number", word)),
+                }
+            }
+            _ => self.error("expected a value"),
+        }
+    }
+
+    /// Characters up to the next separator
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_whitespace() || c == ',' || c == ']' || c == '#' {
+                break;
+            }
+            word.push(c);
+            self.next();
+        }
+        word
+    }
+
+    /// Rest of a `"` string, after the opening quote
+    fn basic_string(&mut self) -> Result<String, Error> {
+        let mut s = String::new();
+        loop {
+            match self.string_char()? {
+                '"' => return Ok(s),
+                '\\' => s.push(self.escape()?),
+                c => s.push(c),
+            }
+        }
+    }
+
+    /// Next character of a single line string
+    fn string_char(&mut self) -> Result<char, Error> {
+        match self.chars.peek() {
+            None | Some('\n') => self.error("unterminated string"),
+            Some(&c) => {
+                self.next();
+                Ok(c)
+            }
+        }
+    }
+
+    /// Rest of a `"""` or `'''` string, after the opening quotes
+    ///
+    /// Only `"""` strings have escapes, `'''` ones are literal.
+    fn multi_line_string(&mut self, delimiter: char) -> Result<String, Error> {
+        let basic = delimiter == '"';
+        // A newline right after the opening quotes is not part of the string
+        self.eat('\r');
+        self.eat('\n');
+        let mut s = String::new();
+        loop {
+            match self.next() {
+                Some(c) if c == delimiter => {
+                    // Three in a row end the string, the text can end in up
+                    // to two more right before them
+                    let mut quotes = 1;
+                    while quotes < 5 && self.eat(delimiter) {
+                        quotes += 1;
+                    }
+                    let text = if quotes >= 3 { quotes - 3 } else { quotes };
+                    for _ in 0..text {
+                        s.push(delimiter);
+                    }
+                    if quotes >= 3 {
+                        return Ok(s);
+                    }
+                }
+                Some('\\') if basic && self.chars.peek().map_or(false, |c| c.is_whitespace()) => {
+                    // Line ending backslash, trims all whitespace up to the next text
+                    while self.chars.peek().map_or(false, |c| c.is_whitespace()) {
+                        self.next();
+                    }
+                }
+                Some('\\') if basic => s.push(self.escape()?),
+                None => return self.error("unterminated string"),
+                Some(c) => s.push(c),
+            }
+        }
+    }
+
+    /// Character of an escape sequence, after the backslash
+    fn escape(&mut self) -> Result<char, Error> {
+        let digits = match self.next() {
+            Some('"') => return Ok('"'),
+            Some('\\') => return Ok('\\'),
+            Some('n') => return Ok('\n'),
+            Some('t') => return Ok('\t'),
+            Some('r') => return Ok('\r'),
+            Some('b') => return Ok('\u{8}'),
+            Some('f') => return Ok('\u{c}'),
+            Some('u') => 4,
+            Some('U') => 8,
+            _ => return self.error("unknown escape sequence"),
+        };
+        let hex: String = (0..digits).filter_map(|_| self.next()).collect();
+        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+            Some(c) if hex.len() == digits => Ok(c),
+            _ => self.error(format!("'\\u{}' is not a valid unicode escape", hex)),
+        }
+    }
+}
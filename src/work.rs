@@ -0,0 +1,53 @@
+//! Also compiled into `build.rs` to read `data/works.toml`, so it must not
+//! use anything from the crate.
+
+/// Book, paper, talk etc. a quote was published in
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Work {
+    pub title: &'static str,
+    pub year: Option<u16>,
+    pub kind: WorkKind,
+}
+
+/// What kind of [`Work`] a quote was published in
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WorkKind {
+    Book,
+    Paper,
+    Talk,
+    MailingList,
+    Interview,
+}
+
+impl WorkKind {
+    /// Every kind of work
+    pub const ALL: [WorkKind; 5] = [
+        WorkKind::Book,
+        WorkKind::Paper,
+        WorkKind::Talk,
+        WorkKind::MailingList,
+        WorkKind::Interview,
+    ];
+}
+
+/// Formats as the title followed by the year, e.g. `Code Complete (1993)`
+impl std::fmt::Display for Work {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{} ({})", self.title, year),
+            None => f.write_str(self.title),
+        }
+    }
+}
+
+impl std::fmt::Display for WorkKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            WorkKind::Book => f.write_str("book"),
+            WorkKind::Paper => f.write_str("paper"),
+            WorkKind::Talk => f.write_str("talk"),
+            WorkKind::MailingList => f.write_str("mailing list"),
+            WorkKind::Interview => f.write_str("interview"),
+        }
+    }
+}
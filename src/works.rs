@@ -0,0 +1,7 @@
+//! Books, papers and talks quoted in [`QUOTES`](crate::QUOTES)
+//!
+//! Generated by `build.rs` from `data/works.toml`.
+
+use crate::{Work, WorkKind};
+
+include!(concat!(env!("OUT_DIR"), "/works.rs"));